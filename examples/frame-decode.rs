@@ -75,94 +75,173 @@ fn main() -> Result<(), Error> {
                 Ok(maybe_frame) => {
                     if let Some(f) = maybe_frame {
                         println!("{}", f);
-                        if f.message_id() == MTData2::MSG_ID {
-                            println!("  MTData2");
-                            let msg = MTData2::decode(&f)?;
-                            for (idx, pkt_result) in msg.into_iter().enumerate() {
-                                let pkt = pkt_result?;
-                                println!("    [{}] {}", idx, pkt);
-                                let data_id = pkt.data_id();
-                                match data_id.data_type() {
-                                    DataType::UtcTime => {
-                                        let data = UtcTime::from_be_slice(pkt.payload())?;
-                                        println!("      {}", data);
-                                    }
-                                    DataType::PacketCounter => {
-                                        let data = PacketCounter::from_be_slice(pkt.payload())?;
-                                        println!("      {}", data);
-                                    }
-                                    DataType::SampleTimeFine => {
-                                        let data = SampleTimeFine::from_be_slice(pkt.payload())?;
-                                        println!("      {}", data);
-                                    }
-                                    DataType::SampleTimeCoarse => {
-                                        let data = SampleTimeCoarse::from_be_slice(pkt.payload())?;
-                                        println!("      {}", data);
-                                    }
-                                    DataType::EulerAngles => {
-                                        if matches!(data_id.precision(), Precision::Float32) {
-                                            let data =
-                                                EulerAngles::<f32>::from_be_slice(pkt.payload())?;
+                        let msg = match AnyMessage::decode(&f) {
+                            Ok(msg) => msg,
+                            Err(e) => {
+                                println!("  Failed to decode message: {}", e);
+                                continue;
+                            }
+                        };
+                        match msg {
+                            AnyMessage::MTData2(msg) => {
+                                println!("  MTData2");
+                                for (idx, pkt_result) in msg.into_iter().enumerate() {
+                                    let pkt = pkt_result?;
+                                    println!("    [{}] {}", idx, pkt);
+                                    let data_id = pkt.data_id();
+                                    match data_id.data_type() {
+                                        DataType::Temperature => match data_id.precision() {
+                                            Precision::Float32 => {
+                                                let data = Temperature::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                            Precision::Float64 => {
+                                                let data = Temperature::<f64>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                            Precision::Fp1220 => {
+                                                let data = Temperature::<u32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      Raw fixed point {}", data);
+                                            }
+                                            Precision::Fp1632 => {
+                                                let data = Temperature::<u64>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      Raw fixed point {}", data);
+                                            }
+                                            Precision::Unknown(_) => (),
+                                        },
+                                        DataType::UtcTime => {
+                                            let data = UtcTime::from_be_slice(pkt.payload())?;
                                             println!("      {}", data);
                                         }
-                                    }
-                                    DataType::Acceleration => {
-                                        if matches!(data_id.precision(), Precision::Float32) {
-                                            let data =
-                                                Acceleration::<f32>::from_be_slice(pkt.payload())?;
+                                        DataType::PacketCounter => {
+                                            let data = PacketCounter::from_be_slice(pkt.payload())?;
                                             println!("      {}", data);
                                         }
-                                    }
-                                    DataType::RateOfTurn => {
-                                        if matches!(data_id.precision(), Precision::Float32) {
+                                        DataType::SampleTimeFine => {
                                             let data =
-                                                RateOfTurn::<f32>::from_be_slice(pkt.payload())?;
+                                                SampleTimeFine::from_be_slice(pkt.payload())?;
                                             println!("      {}", data);
                                         }
-                                    }
-                                    DataType::MagneticField => {
-                                        if matches!(data_id.precision(), Precision::Float32) {
+                                        DataType::SampleTimeCoarse => {
                                             let data =
-                                                MagneticField::<f32>::from_be_slice(pkt.payload())?;
+                                                SampleTimeCoarse::from_be_slice(pkt.payload())?;
                                             println!("      {}", data);
                                         }
-                                    }
-                                    DataType::AltitudeEllipsoid => {
-                                        if matches!(data_id.precision(), Precision::Float64) {
-                                            let data = AltitudeEllipsoid::<f64>::from_be_slice(
-                                                pkt.payload(),
-                                            )?;
-                                            println!("      {}", data);
+                                        DataType::Quaternion => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = Quaternion::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                                println!("      {}", data.to_euler_angles());
+                                            }
                                         }
-                                    }
-                                    DataType::PositionEcef => {
-                                        if matches!(data_id.precision(), Precision::Float64) {
-                                            let data =
-                                                PositionEcef::<f64>::from_be_slice(pkt.payload())?;
-                                            println!("      {}", data);
+                                        DataType::EulerAngles => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = EulerAngles::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
                                         }
-                                    }
-                                    DataType::LatLon => {
-                                        if matches!(data_id.precision(), Precision::Float64) {
-                                            let data = LatLon::<f64>::from_be_slice(pkt.payload())?;
-                                            println!("      {}", data);
+                                        DataType::Acceleration => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = Acceleration::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
                                         }
-                                    }
-                                    DataType::VelocityXYZ => {
-                                        if matches!(data_id.precision(), Precision::Float32) {
-                                            let data =
-                                                VelocityXYZ::<f32>::from_be_slice(pkt.payload())?;
+                                        DataType::FreeAcceleration => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = FreeAcceleration::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::AccelerationHR => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = AccelerationHr::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::RateOfTurn => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = RateOfTurn::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::RateOfTurnHr => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = RateOfTurnHr::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::MagneticField => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = MagneticField::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::AltitudeEllipsoid => {
+                                            if matches!(data_id.precision(), Precision::Float64) {
+                                                let data = AltitudeEllipsoid::<f64>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::PositionEcef => {
+                                            if matches!(data_id.precision(), Precision::Float64) {
+                                                let data = PositionEcef::<f64>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::LatLon => {
+                                            if matches!(data_id.precision(), Precision::Float64) {
+                                                let data =
+                                                    LatLon::<f64>::from_be_slice(pkt.payload())?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::VelocityXYZ => {
+                                            if matches!(data_id.precision(), Precision::Float32) {
+                                                let data = VelocityXYZ::<f32>::from_be_slice(
+                                                    pkt.payload(),
+                                                )?;
+                                                println!("      {}", data);
+                                            }
+                                        }
+                                        DataType::StatusWord => {
+                                            let data = StatusWord::from_be_slice(pkt.payload())?;
                                             println!("      {}", data);
                                         }
-                                    }
-                                    DataType::StatusWord => {
-                                        let data = StatusWord::from_be_slice(pkt.payload())?;
-                                        println!("      {}", data);
-                                    }
 
-                                    _ => (),
+                                        _ => (),
+                                    }
                                 }
                             }
+                            AnyMessage::Error(msg) => println!("  Error: {}", msg.code),
+                            _ => (),
                         }
                     }
                 }
@@ -24,7 +24,9 @@ fuzz_target!(|input: Input| {
     let precision = data_id.precision();
     let bytes = &input.data[..];
     match data_type {
-        DataType::Temperature => (), // TODO no type for this yet
+        DataType::Temperature => {
+            parse_any_precision_variant_from_be_slice!(Temperature, precision, bytes);
+        }
         DataType::UtcTime => {
             let _ = UtcTime::from_be_slice(bytes);
         }
@@ -37,13 +39,27 @@ fuzz_target!(|input: Input| {
         DataType::SampleTimeCoarse => {
             let _ = SampleTimeCoarse::from_be_slice(bytes);
         }
-        DataType::Quaternion => (), // TODO no type for this yet
+        DataType::Quaternion => {
+            parse_any_precision_variant_from_be_slice!(Quaternion, precision, bytes);
+        }
+        DataType::RotationMatrix => {
+            parse_any_precision_variant_from_be_slice!(RotationMatrix, precision, bytes);
+        }
         DataType::EulerAngles => {
             parse_any_precision_variant_from_be_slice!(EulerAngles, precision, bytes);
         }
+        DataType::DeltaV => {
+            parse_any_precision_variant_from_be_slice!(DeltaV, precision, bytes);
+        }
         DataType::Acceleration => {
             parse_any_precision_variant_from_be_slice!(Acceleration, precision, bytes);
         }
+        DataType::FreeAcceleration => {
+            parse_any_precision_variant_from_be_slice!(FreeAcceleration, precision, bytes);
+        }
+        DataType::AccelerationHR => {
+            parse_any_precision_variant_from_be_slice!(AccelerationHr, precision, bytes);
+        }
         DataType::AltitudeEllipsoid => {
             parse_any_precision_variant_from_be_slice!(AltitudeEllipsoid, precision, bytes);
         }
@@ -53,9 +69,15 @@ fuzz_target!(|input: Input| {
         DataType::LatLon => {
             parse_any_precision_variant_from_be_slice!(LatLon, precision, bytes);
         }
+        DataType::DeltaQ => {
+            parse_any_precision_variant_from_be_slice!(DeltaQ, precision, bytes);
+        }
         DataType::RateOfTurn => {
             parse_any_precision_variant_from_be_slice!(RateOfTurn, precision, bytes);
         }
+        DataType::RateOfTurnHr => {
+            parse_any_precision_variant_from_be_slice!(RateOfTurnHr, precision, bytes);
+        }
         DataType::MagneticField => {
             parse_any_precision_variant_from_be_slice!(MagneticField, precision, bytes);
         }
@@ -0,0 +1,190 @@
+//! Polling mode helper
+//!
+//! Instead of streaming, the device can be polled for a single sample
+//! by sending a [`ReqData`] message. The device responds with an [`MTData2`] message
+//! which is decoded the same way as the streamed data.
+//! If the device rejects the request, for example when it is not in Measurement State,
+//! it responds with an [`Error`](crate::messages::Error) message instead.
+
+use crate::decoder::{Decoder, Error};
+use crate::encoder::encode_frame;
+use crate::message::{BusId, Frame, MessageDecode, MessageEncode, MessageExt};
+use crate::messages::{self, MTData2, ReqData};
+
+/// Size of the ReqData frame written for each request
+const REQ_FRAME_SIZE: usize = Frame::<&[u8]>::buffer_len(ReqData::MAX_PAYLOAD_LEN);
+
+/// The device's response to a pending [`ReqData`] request
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PollResponse<'a> {
+    /// The requested sample
+    Data(MTData2<'a>),
+    /// The device rejected the request
+    Error(messages::Error<'a>),
+}
+
+#[derive(Debug)]
+pub struct DataPoller<B: AsRef<[u8]> + AsMut<[u8]>> {
+    decoder: Decoder<B>,
+    pending: bool,
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> DataPoller<B> {
+    pub fn new(decoder: Decoder<B>) -> Self {
+        DataPoller {
+            decoder,
+            pending: false,
+        }
+    }
+
+    /// Returns true while a request has been sent and its response has not
+    /// yet been received
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Cancel the pending request, i.e. when the device did not respond in time.
+    ///
+    /// A late response to the cancelled request is ignored like any other frame.
+    pub fn cancel(&mut self) {
+        self.pending = false;
+    }
+
+    pub fn into_inner(self) -> Decoder<B> {
+        self.decoder
+    }
+
+    /// Pass a ReqData frame to `write` and wait for the next MTData2 or Error frame
+    pub fn request<E, F>(&mut self, mut write: F) -> Result<(), E>
+    where
+        E: From<Error>,
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
+        let mut req = [0_u8; REQ_FRAME_SIZE];
+        encode_frame(&ReqData, BusId::MASTER, &mut req).map_err(Error::from)?;
+        write(&req)?;
+        self.pending = true;
+        Ok(())
+    }
+
+    /// Decode a byte received from the device.
+    ///
+    /// Returns the response to the pending request once it has been received,
+    /// all other frames are ignored.
+    pub fn poll(&mut self, byte: u8) -> Result<Option<PollResponse<'_>>, Error> {
+        let pending = &mut self.pending;
+        match self.decoder.decode(byte)? {
+            Some(f) if *pending && f.message_id() == MTData2::MSG_ID => {
+                *pending = false;
+                Ok(Some(PollResponse::Data(MTData2::decode(&f)?)))
+            }
+            Some(f) if *pending && f.message_id() == messages::Error::MSG_ID => {
+                *pending = false;
+                Ok(Some(PollResponse::Error(messages::Error::decode(&f)?)))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ErrorCode;
+    use crate::mtdata2::PacketCounter;
+    use crate::wire::DataType;
+    use pretty_assertions::assert_eq;
+
+    static REQ_DATA_MSG: [u8; 5] = [0xFA, 0xFF, 0x34, 0x00, 0xCD];
+    static ERROR_MSG: [u8; 6] = [0xFA, 0xFF, 0x42, 0x01, 0x04, 0xBA];
+
+    #[rustfmt::skip]
+    static MTDATA2_MSG: [u8; 10] = [
+        0xFA, 0xFF, 0x36, 0x05,
+        0x10, 0x20, 0x02, 0xBE, 0xC2,
+        0x14,
+    ];
+
+    #[test]
+    fn request_and_poll() {
+        let mut buffer = [0_u8; 512];
+        let dec = Decoder::new(&mut buffer[..]).unwrap();
+        let mut poller = DataPoller::new(dec);
+
+        // Not requested, ignored
+        for byte in MTDATA2_MSG.iter() {
+            assert!(poller.poll(*byte).unwrap().is_none());
+        }
+
+        let mut written = [0_u8; 5];
+        poller
+            .request(|bytes: &[u8]| -> Result<(), Error> {
+                written.copy_from_slice(bytes);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(written, REQ_DATA_MSG);
+        assert!(poller.is_pending());
+
+        let (last, rest) = MTDATA2_MSG.split_last().unwrap();
+        for byte in rest.iter() {
+            assert!(poller.poll(*byte).unwrap().is_none());
+        }
+        let msg = match poller.poll(*last).unwrap().unwrap() {
+            PollResponse::Data(msg) => msg,
+            r => panic!("unexpected response {:?}", r),
+        };
+        let pkt = msg.into_iter().next().unwrap().unwrap();
+        assert_eq!(pkt.data_id().data_type(), DataType::PacketCounter);
+        assert_eq!(
+            PacketCounter::from_be_slice(pkt.payload()).unwrap(),
+            PacketCounter(0xBEC2)
+        );
+        assert!(!poller.is_pending());
+    }
+
+    #[test]
+    fn request_rejected() {
+        let mut buffer = [0_u8; 512];
+        let dec = Decoder::new(&mut buffer[..]).unwrap();
+        let mut poller = DataPoller::new(dec);
+        poller
+            .request(|_: &[u8]| -> Result<(), Error> { Ok(()) })
+            .unwrap();
+
+        let (last, rest) = ERROR_MSG.split_last().unwrap();
+        for byte in rest.iter() {
+            assert!(poller.poll(*byte).unwrap().is_none());
+        }
+        assert_eq!(
+            poller.poll(*last).unwrap(),
+            Some(PollResponse::Error(messages::Error {
+                code: ErrorCode::MessageInvalid,
+                data: &[],
+            }))
+        );
+        assert!(!poller.is_pending());
+
+        // The next sample is not mistaken for a response
+        for byte in MTDATA2_MSG.iter() {
+            assert!(poller.poll(*byte).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn cancel() {
+        let mut buffer = [0_u8; 512];
+        let dec = Decoder::new(&mut buffer[..]).unwrap();
+        let mut poller = DataPoller::new(dec);
+        poller
+            .request(|_: &[u8]| -> Result<(), Error> { Ok(()) })
+            .unwrap();
+        assert!(poller.is_pending());
+        poller.cancel();
+        assert!(!poller.is_pending());
+
+        for byte in MTDATA2_MSG.iter() {
+            assert!(poller.poll(*byte).unwrap().is_none());
+        }
+    }
+}
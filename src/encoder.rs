@@ -0,0 +1,150 @@
+//! A basic MT protocol frame encoder
+
+use crate::message::{BusId, Frame, FrameError, MessageEncode};
+
+/// Encode a complete frame, including the preamble, bus id and checksum, into `buffer`.
+///
+/// Returns the number of bytes written.
+pub fn encode_frame<M: MessageEncode>(
+    msg: &M,
+    bus_id: BusId,
+    buffer: &mut [u8],
+) -> Result<usize, FrameError> {
+    let len = msg.encoded_len();
+    if buffer.len() < len {
+        return Err(FrameError::BufferTooSmall);
+    }
+    let mut frame = Frame::new_unchecked(&mut buffer[..len]);
+    frame.set_preamble();
+    frame.set_bus_id(bus_id);
+    msg.encode(&mut frame)?;
+    let checksum = frame.compute_checksum_byte()?;
+    frame.set_checksum(checksum)?;
+    Ok(len)
+}
+
+#[derive(Debug)]
+pub struct Encoder<B: AsRef<[u8]> + AsMut<[u8]>> {
+    buffer: B,
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Encoder<B> {
+    pub fn new(buffer: B) -> Self {
+        Encoder { buffer }
+    }
+
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+
+    /// Encode a complete frame into the encoder's buffer, returning the encoded bytes
+    pub fn encode<M: MessageEncode>(
+        &mut self,
+        msg: &M,
+        bus_id: BusId,
+    ) -> Result<&[u8], FrameError> {
+        let len = encode_frame(msg, bus_id, self.buffer.as_mut())?;
+        Ok(&self.buffer.as_ref()[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        BaudRate, Error, ErrorCode, GoToConfig, ProductCode, SetBaudrate, SetLatLonAlt,
+    };
+    use crate::mtdata2::{AltitudeEllipsoid, LatLon};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn encode_empty_payload() {
+        let mut buffer = [0_u8; Frame::<&[u8]>::buffer_len(0)];
+        let len = encode_frame(&GoToConfig, BusId::MASTER, &mut buffer).unwrap();
+        assert_eq!(len, 5);
+        assert_eq!(buffer, [0xFA, 0xFF, 0x30, 0x00, 0xD1]);
+    }
+
+    #[test]
+    fn encoder() {
+        let mut buffer = [0_u8; 64];
+        let mut enc = Encoder::new(&mut buffer[..]);
+        let bytes = enc
+            .encode(&SetBaudrate(BaudRate::Baud115200), BusId::MASTER)
+            .unwrap();
+        assert_eq!(bytes, &[0xFA, 0xFF, 0x18, 0x01, 0x02, 0xE6]);
+        assert!(Frame::new(bytes).is_ok());
+    }
+
+    #[test]
+    fn encoded_len() {
+        let msg = SetLatLonAlt::new(
+            LatLon {
+                lat: 47.673,
+                lon: -117.153,
+            },
+            AltitudeEllipsoid(603.645),
+        );
+        assert_eq!(
+            msg.encoded_len(),
+            Frame::<&[u8]>::buffer_len(SetLatLonAlt::WIRE_SIZE)
+        );
+        assert_eq!(GoToConfig.encoded_len(), 5);
+
+        let code = ProductCode("MTi-680G-2A8G4-DK-OVERSIZE");
+        assert_eq!(code.payload_len(), ProductCode::MAX_PAYLOAD_LEN);
+        let mut buffer = [0_u8; Frame::<&[u8]>::buffer_len(ProductCode::MAX_PAYLOAD_LEN)];
+        assert_eq!(
+            encode_frame(&code, BusId::MASTER, &mut buffer),
+            Err(FrameError::InvalidMessagePayload)
+        );
+
+        let err = Error {
+            code: ErrorCode::PeriodInvalid,
+            data: &[0x01, 0x02],
+        };
+        assert_eq!(err.encoded_len(), Frame::<&[u8]>::buffer_len(3));
+
+        // Never larger than the compile time buffer size
+        let data = [0_u8; Error::MAX_PAYLOAD_LEN];
+        let err = Error {
+            code: ErrorCode::PeriodInvalid,
+            data: &data,
+        };
+        assert_eq!(err.payload_len(), Error::MAX_PAYLOAD_LEN);
+        let mut buffer = [0_u8; Frame::<&[u8]>::buffer_len(Error::MAX_PAYLOAD_LEN)];
+        assert_eq!(
+            encode_frame(&err, BusId::MASTER, &mut buffer),
+            Err(FrameError::InvalidPayloadLength)
+        );
+    }
+
+    #[test]
+    fn const_buffer_size() {
+        let mut buffer = [0_u8; Frame::<&[u8]>::buffer_len(SetBaudrate::MAX_PAYLOAD_LEN)];
+        let len = encode_frame(
+            &SetBaudrate(BaudRate::Baud115200),
+            BusId::MASTER,
+            &mut buffer,
+        )
+        .unwrap();
+        assert_eq!(len, buffer.len());
+    }
+
+    #[test]
+    fn insufficient_buffer() {
+        let mut buffer = [0_u8; 5];
+        assert_eq!(
+            encode_frame(
+                &SetBaudrate(BaudRate::Baud115200),
+                BusId::MASTER,
+                &mut buffer
+            ),
+            Err(FrameError::BufferTooSmall)
+        );
+        assert_eq!(
+            encode_frame(&GoToConfig, BusId::MASTER, &mut buffer[..3]),
+            Err(FrameError::BufferTooSmall)
+        );
+    }
+}
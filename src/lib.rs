@@ -8,10 +8,13 @@
 #[macro_use]
 mod macros;
 
+pub mod data_poller;
 pub mod decoder;
+pub mod encoder;
 pub mod message;
 pub mod messages;
 pub mod mtdata2;
 pub mod precision;
 pub mod prelude;
+pub mod wake_up_handler;
 pub mod wire;
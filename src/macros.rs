@@ -575,3 +575,574 @@ macro_rules! precision_fp1632_3field_wire_impl {
         }
     };
 }
+
+macro_rules! precision_float32_4field_wire_impl {
+    ($name:ident, $f0:ident, $f1:ident, $f2:ident, $f3:ident) => {
+        mod generated_4field_float32 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field32, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = f32;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    4 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field32::F0]);
+                        let $f1 = Inner::read_field(&bytes[field32::F1]);
+                        let $f2 = Inner::read_field(&bytes[field32::F2]);
+                        let $f3 = Inner::read_field(&bytes[field32::F3]);
+                        Ok($crate::prelude::$name { $f0, $f1, $f2, $f3 })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_4field_float32 {
+            use crate::prelude::$name;
+            use approx::assert_relative_eq;
+            // Big-endian 1.1f, 2.2f, 3.3f, 4.4f
+            const BYTES: [u8; 16] = [
+                0x3F, 0x8C, 0xCC, 0xCD, 0x40, 0x0C, 0xCC, 0xCD, 0x40, 0x53, 0x33, 0x33, 0x40, 0x8C,
+                0xCC, 0xCD,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<f32>::WIRE_SIZE);
+                let t = $name::<f32>::from_be_slice(&BYTES).unwrap();
+                assert_relative_eq!(t.$f0, 1.1);
+                assert_relative_eq!(t.$f1, 2.2);
+                assert_relative_eq!(t.$f2, 3.3);
+                assert_relative_eq!(t.$f3, 4.4);
+            }
+        }
+    };
+}
+
+macro_rules! precision_fp1220_4field_wire_impl {
+    ($name:ident, $f0:ident, $f1:ident, $f2:ident, $f3:ident) => {
+        mod generated_4field_fp1220 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field32, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = u32;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    4 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field32::F0]);
+                        let $f1 = Inner::read_field(&bytes[field32::F1]);
+                        let $f2 = Inner::read_field(&bytes[field32::F2]);
+                        let $f3 = Inner::read_field(&bytes[field32::F3]);
+                        Ok($crate::prelude::$name { $f0, $f1, $f2, $f3 })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_4field_fp1220 {
+            use crate::prelude::$name;
+            // Big-endian 1, 2, 3, 4
+            const BYTES: [u8; 16] = [
+                0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+                0x00, 0x04,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<u32>::WIRE_SIZE);
+                let t = $name::<u32>::from_be_slice(&BYTES).unwrap();
+                assert_eq!(t.$f0, 1);
+                assert_eq!(t.$f1, 2);
+                assert_eq!(t.$f2, 3);
+                assert_eq!(t.$f3, 4);
+            }
+        }
+    };
+}
+
+macro_rules! precision_float64_4field_wire_impl {
+    ($name:ident, $f0:ident, $f1:ident, $f2:ident, $f3:ident) => {
+        mod generated_4field_float64 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field64, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = f64;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    4 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field64::F0]);
+                        let $f1 = Inner::read_field(&bytes[field64::F1]);
+                        let $f2 = Inner::read_field(&bytes[field64::F2]);
+                        let $f3 = Inner::read_field(&bytes[field64::F3]);
+                        Ok($crate::prelude::$name { $f0, $f1, $f2, $f3 })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_4field_float64 {
+            use crate::prelude::$name;
+            use approx::assert_relative_eq;
+            // Big-endian 1.1f, 2.2f, 3.3f, 4.4f
+            const BYTES: [u8; 32] = [
+                0x3F, 0xF1, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A, 0x40, 0x01, 0x99, 0x99, 0x99, 0x99,
+                0x99, 0x9A, 0x40, 0x0A, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x40, 0x11, 0x99, 0x99,
+                0x99, 0x99, 0x99, 0x9A,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<f64>::WIRE_SIZE);
+                let t = $name::<f64>::from_be_slice(&BYTES).unwrap();
+                assert_relative_eq!(t.$f0, 1.1);
+                assert_relative_eq!(t.$f1, 2.2);
+                assert_relative_eq!(t.$f2, 3.3);
+                assert_relative_eq!(t.$f3, 4.4);
+            }
+        }
+    };
+}
+
+macro_rules! precision_fp1632_4field_wire_impl {
+    ($name:ident, $f0:ident, $f1:ident, $f2:ident, $f3:ident) => {
+        mod generated_4field_fp1632 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field64, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = u64;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    4 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field64::F0]);
+                        let $f1 = Inner::read_field(&bytes[field64::F1]);
+                        let $f2 = Inner::read_field(&bytes[field64::F2]);
+                        let $f3 = Inner::read_field(&bytes[field64::F3]);
+                        Ok($crate::prelude::$name { $f0, $f1, $f2, $f3 })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_4field_fp1632 {
+            use crate::prelude::$name;
+            // Big-endian 1, 2, 3, 4
+            const BYTES: [u8; 32] = [
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x04,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<u64>::WIRE_SIZE);
+                let t = $name::<u64>::from_be_slice(&BYTES).unwrap();
+                assert_eq!(t.$f0, 1);
+                assert_eq!(t.$f1, 2);
+                assert_eq!(t.$f2, 3);
+                assert_eq!(t.$f3, 4);
+            }
+        }
+    };
+}
+
+macro_rules! precision_float32_9field_wire_impl {
+    (
+        $name:ident,
+        $f0:ident, $f1:ident, $f2:ident,
+        $f3:ident, $f4:ident, $f5:ident,
+        $f6:ident, $f7:ident, $f8:ident
+    ) => {
+        mod generated_9field_float32 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field32, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = f32;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    9 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field32::F0]);
+                        let $f1 = Inner::read_field(&bytes[field32::F1]);
+                        let $f2 = Inner::read_field(&bytes[field32::F2]);
+                        let $f3 = Inner::read_field(&bytes[field32::F3]);
+                        let $f4 = Inner::read_field(&bytes[field32::F4]);
+                        let $f5 = Inner::read_field(&bytes[field32::F5]);
+                        let $f6 = Inner::read_field(&bytes[field32::F6]);
+                        let $f7 = Inner::read_field(&bytes[field32::F7]);
+                        let $f8 = Inner::read_field(&bytes[field32::F8]);
+                        Ok($crate::prelude::$name {
+                            $f0,
+                            $f1,
+                            $f2,
+                            $f3,
+                            $f4,
+                            $f5,
+                            $f6,
+                            $f7,
+                            $f8,
+                        })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_9field_float32 {
+            use crate::prelude::$name;
+            use approx::assert_relative_eq;
+            // Big-endian 1.1f, 2.2f, 3.3f, 4.4f, 5.5f, 6.6f, 7.7f, 8.8f, 9.9f
+            const BYTES: [u8; 36] = [
+                0x3F, 0x8C, 0xCC, 0xCD, 0x40, 0x0C, 0xCC, 0xCD, 0x40, 0x53, 0x33, 0x33, 0x40, 0x8C,
+                0xCC, 0xCD, 0x40, 0xB0, 0x00, 0x00, 0x40, 0xD3, 0x33, 0x33, 0x40, 0xF6, 0x66, 0x66,
+                0x41, 0x0C, 0xCC, 0xCD, 0x41, 0x1E, 0x66, 0x66,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<f32>::WIRE_SIZE);
+                let t = $name::<f32>::from_be_slice(&BYTES).unwrap();
+                assert_relative_eq!(t.$f0, 1.1);
+                assert_relative_eq!(t.$f1, 2.2);
+                assert_relative_eq!(t.$f2, 3.3);
+                assert_relative_eq!(t.$f3, 4.4);
+                assert_relative_eq!(t.$f4, 5.5);
+                assert_relative_eq!(t.$f5, 6.6);
+                assert_relative_eq!(t.$f6, 7.7);
+                assert_relative_eq!(t.$f7, 8.8);
+                assert_relative_eq!(t.$f8, 9.9);
+            }
+        }
+    };
+}
+
+macro_rules! precision_fp1220_9field_wire_impl {
+    (
+        $name:ident,
+        $f0:ident, $f1:ident, $f2:ident,
+        $f3:ident, $f4:ident, $f5:ident,
+        $f6:ident, $f7:ident, $f8:ident
+    ) => {
+        mod generated_9field_fp1220 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field32, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = u32;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    9 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field32::F0]);
+                        let $f1 = Inner::read_field(&bytes[field32::F1]);
+                        let $f2 = Inner::read_field(&bytes[field32::F2]);
+                        let $f3 = Inner::read_field(&bytes[field32::F3]);
+                        let $f4 = Inner::read_field(&bytes[field32::F4]);
+                        let $f5 = Inner::read_field(&bytes[field32::F5]);
+                        let $f6 = Inner::read_field(&bytes[field32::F6]);
+                        let $f7 = Inner::read_field(&bytes[field32::F7]);
+                        let $f8 = Inner::read_field(&bytes[field32::F8]);
+                        Ok($crate::prelude::$name {
+                            $f0,
+                            $f1,
+                            $f2,
+                            $f3,
+                            $f4,
+                            $f5,
+                            $f6,
+                            $f7,
+                            $f8,
+                        })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_9field_fp1220 {
+            use crate::prelude::$name;
+            // Big-endian 1, 2, 3, 4, 5, 6, 7, 8, 9
+            const BYTES: [u8; 36] = [
+                0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+                0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07,
+                0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x09,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<u32>::WIRE_SIZE);
+                let t = $name::<u32>::from_be_slice(&BYTES).unwrap();
+                assert_eq!(t.$f0, 1);
+                assert_eq!(t.$f1, 2);
+                assert_eq!(t.$f2, 3);
+                assert_eq!(t.$f3, 4);
+                assert_eq!(t.$f4, 5);
+                assert_eq!(t.$f5, 6);
+                assert_eq!(t.$f6, 7);
+                assert_eq!(t.$f7, 8);
+                assert_eq!(t.$f8, 9);
+            }
+        }
+    };
+}
+
+macro_rules! precision_float64_9field_wire_impl {
+    (
+        $name:ident,
+        $f0:ident, $f1:ident, $f2:ident,
+        $f3:ident, $f4:ident, $f5:ident,
+        $f6:ident, $f7:ident, $f8:ident
+    ) => {
+        mod generated_9field_float64 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field64, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = f64;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    9 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field64::F0]);
+                        let $f1 = Inner::read_field(&bytes[field64::F1]);
+                        let $f2 = Inner::read_field(&bytes[field64::F2]);
+                        let $f3 = Inner::read_field(&bytes[field64::F3]);
+                        let $f4 = Inner::read_field(&bytes[field64::F4]);
+                        let $f5 = Inner::read_field(&bytes[field64::F5]);
+                        let $f6 = Inner::read_field(&bytes[field64::F6]);
+                        let $f7 = Inner::read_field(&bytes[field64::F7]);
+                        let $f8 = Inner::read_field(&bytes[field64::F8]);
+                        Ok($crate::prelude::$name {
+                            $f0,
+                            $f1,
+                            $f2,
+                            $f3,
+                            $f4,
+                            $f5,
+                            $f6,
+                            $f7,
+                            $f8,
+                        })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_9field_float64 {
+            use crate::prelude::$name;
+            use approx::assert_relative_eq;
+            // Big-endian 1.1f, 2.2f, 3.3f, 4.4f, 5.5f, 6.6f, 7.7f, 8.8f, 9.9f
+            const BYTES: [u8; 72] = [
+                0x3F, 0xF1, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A, 0x40, 0x01, 0x99, 0x99, 0x99, 0x99,
+                0x99, 0x9A, 0x40, 0x0A, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x40, 0x11, 0x99, 0x99,
+                0x99, 0x99, 0x99, 0x9A, 0x40, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x1A,
+                0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x40, 0x1E, 0xCC, 0xCC, 0xCC, 0xCC, 0xCC, 0xCD,
+                0x40, 0x21, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A, 0x40, 0x23, 0xCC, 0xCC, 0xCC, 0xCC,
+                0xCC, 0xCD,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<f64>::WIRE_SIZE);
+                let t = $name::<f64>::from_be_slice(&BYTES).unwrap();
+                assert_relative_eq!(t.$f0, 1.1);
+                assert_relative_eq!(t.$f1, 2.2);
+                assert_relative_eq!(t.$f2, 3.3);
+                assert_relative_eq!(t.$f3, 4.4);
+                assert_relative_eq!(t.$f4, 5.5);
+                assert_relative_eq!(t.$f5, 6.6);
+                assert_relative_eq!(t.$f6, 7.7);
+                assert_relative_eq!(t.$f7, 8.8);
+                assert_relative_eq!(t.$f8, 9.9);
+            }
+        }
+    };
+}
+
+macro_rules! precision_fp1632_9field_wire_impl {
+    (
+        $name:ident,
+        $f0:ident, $f1:ident, $f2:ident,
+        $f3:ident, $f4:ident, $f5:ident,
+        $f6:ident, $f7:ident, $f8:ident
+    ) => {
+        mod generated_9field_fp1632 {
+            use crate::precision::PrecisionExt;
+            use crate::wire::{field64, WireError};
+            use core::mem;
+            use static_assertions::assert_eq_size;
+
+            type Inner = u64;
+            assert_eq_size!(Inner, <Inner as PrecisionExt>::NativeType);
+
+            impl $crate::prelude::$name<Inner> {
+                pub const WIRE_SIZE: usize =
+                    9 * mem::size_of::<<Inner as PrecisionExt>::NativeType>();
+
+                pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+                    if bytes.len() < Self::WIRE_SIZE {
+                        Err(WireError::MissingBytes)
+                    } else {
+                        let $f0 = Inner::read_field(&bytes[field64::F0]);
+                        let $f1 = Inner::read_field(&bytes[field64::F1]);
+                        let $f2 = Inner::read_field(&bytes[field64::F2]);
+                        let $f3 = Inner::read_field(&bytes[field64::F3]);
+                        let $f4 = Inner::read_field(&bytes[field64::F4]);
+                        let $f5 = Inner::read_field(&bytes[field64::F5]);
+                        let $f6 = Inner::read_field(&bytes[field64::F6]);
+                        let $f7 = Inner::read_field(&bytes[field64::F7]);
+                        let $f8 = Inner::read_field(&bytes[field64::F8]);
+                        Ok($crate::prelude::$name {
+                            $f0,
+                            $f1,
+                            $f2,
+                            $f3,
+                            $f4,
+                            $f5,
+                            $f6,
+                            $f7,
+                            $f8,
+                        })
+                    }
+                }
+            }
+        }
+        #[cfg(test)]
+        mod generated_tests_9field_fp1632 {
+            use crate::prelude::$name;
+            // Big-endian 1, 2, 3, 4, 5, 6, 7, 8, 9
+            const BYTES: [u8; 72] = [
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x09,
+            ];
+            #[test]
+            fn decode_from_be_slice() {
+                assert_eq!(BYTES.len(), $name::<u64>::WIRE_SIZE);
+                let t = $name::<u64>::from_be_slice(&BYTES).unwrap();
+                assert_eq!(t.$f0, 1);
+                assert_eq!(t.$f1, 2);
+                assert_eq!(t.$f2, 3);
+                assert_eq!(t.$f3, 4);
+                assert_eq!(t.$f4, 5);
+                assert_eq!(t.$f5, 6);
+                assert_eq!(t.$f6, 7);
+                assert_eq!(t.$f7, 8);
+                assert_eq!(t.$f8, 9);
+            }
+        }
+    };
+}
+
+/// Adds a `decode_batch` fast path for high rate 3-field types, decoding the
+/// matching packets of a raw MTData2 payload straight into a caller provided buffer
+macro_rules! batch_decode_3field_impl {
+    ($name:ident, $data_type:ident) => {
+        batch_decode_3field_impl!(@impl $name, $data_type, f32);
+        batch_decode_3field_impl!(@impl $name, $data_type, f64);
+        batch_decode_3field_impl!(@impl $name, $data_type, u32);
+        batch_decode_3field_impl!(@impl $name, $data_type, u64);
+    };
+    (@impl $name:ident, $data_type:ident, $inner:ty) => {
+        impl $crate::prelude::$name<$inner> {
+            /// Decode every packet of this data type and precision in the raw
+            /// MTData2 `payload` (i.e. the [`Frame::payload`](crate::message::Frame::payload)
+            /// of an MTData2 message) into `out`, skipping all other packets.
+            ///
+            /// Returns the number of samples written, which stops short of the
+            /// remaining packets when `out` is full.
+            ///
+            /// The packet headers are walked directly on the raw bytes, without
+            /// constructing a [`MTData2Packet`](crate::wire::MTData2Packet) per packet,
+            /// and only the matching packets are decoded.
+            pub fn decode_batch(
+                payload: &[u8],
+                out: &mut [Self],
+            ) -> Result<usize, $crate::wire::WireError> {
+                use $crate::precision::PrecisionExt;
+                use $crate::wire::{CoordinateSystem, DataId, DataType, MTData2Packet, WireError};
+                use byteorder::{BigEndian, ByteOrder};
+
+                // Any coordinate system matches
+                const COORDINATE_SYSTEM_MASK: u16 = 0b1100;
+                let data_id = DataId::new(
+                    DataType::$data_type,
+                    <$inner>::PRECISION,
+                    CoordinateSystem::default(),
+                )
+                .to_wire();
+                let header_len = MTData2Packet::<&[u8]>::header_len();
+
+                let mut cursor = 0;
+                let mut count = 0;
+                while count < out.len() && cursor < payload.len() {
+                    let bytes = &payload[cursor..];
+                    if bytes.len() < header_len {
+                        return Err(WireError::MissingBytes);
+                    }
+                    // The data length is the last byte of the header
+                    let end = header_len + usize::from(bytes[header_len - 1]);
+                    if bytes.len() < end {
+                        return Err(WireError::MissingBytes);
+                    }
+                    if BigEndian::read_u16(bytes) & !COORDINATE_SYSTEM_MASK == data_id {
+                        out[count] = Self::from_be_slice(&bytes[header_len..end])?;
+                        count += 1;
+                    }
+                    cursor += end;
+                }
+                Ok(count)
+            }
+        }
+    };
+}
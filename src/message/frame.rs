@@ -40,6 +40,12 @@ pub enum FrameError {
 
     #[error(display = "Invalid checksum")]
     InvalidChecksum,
+
+    #[error(display = "Payload does not match the layout of the message")]
+    InvalidMessagePayload,
+
+    #[error(display = "Not enough space in the buffer for the encoded frame")]
+    BufferTooSmall,
 }
 
 #[derive(Debug, Clone)]
@@ -51,9 +57,9 @@ impl<T: AsRef<[u8]>> fmt::Display for Frame<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "BusId(0x{:X}), MsgId(0x{:X}), Len({:?})",
+            "BusId(0x{:X}), {}, Len({:?})",
             self.bus_id().0,
-            self.message_id().0,
+            self.message_id(),
             self.payload_length().map_err(|_| fmt::Error)?
         )
     }
@@ -159,7 +165,7 @@ impl<T: AsRef<[u8]>> Frame<T> {
     /// Return the length of a buffer required to hold a message
     /// with a payload length of `n_payload_bytes` + `CHECKSUM_SIZE`.
     #[inline]
-    pub fn buffer_len(n_payload_bytes: usize) -> usize {
+    pub const fn buffer_len(n_payload_bytes: usize) -> usize {
         let header_size = if n_payload_bytes > (PayloadLength::MAX_STD as usize) {
             field::PAYLOAD_EXT.start
         } else {
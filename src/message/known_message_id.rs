@@ -0,0 +1,313 @@
+use crate::message::MessageId;
+use core::fmt;
+
+enum_with_unknown! {
+    /// The message identifiers of the MT Low-Level Communication Protocol.
+    ///
+    /// Settings that can be both requested and set share an identifier, i.e.
+    /// `ReqBaudrate` and `SetBaudrate` are both [`KnownMessageId::SetBaudrate`].
+    /// The acknowledgement of a message uses the identifier of the message plus one,
+    /// see [`KnownMessageId::ack`].
+    ///
+    /// Covers the MTi 1-, 10- and 100-series tables of the MT Low-Level Communication
+    /// Protocol, including the legacy messages still listed there.
+    /// The MTw/Awinda wireless messages and the firmware update (bootloader) messages
+    /// are documented separately and are not included.
+    pub enum KnownMessageId(u8) {
+        ReqDid = 0x00,
+        DeviceId = 0x01,
+        SetPeriod = 0x04,
+        SetPeriodAck = 0x05,
+        ReqDataLength = 0x0A,
+        DataLength = 0x0B,
+        ReqConfiguration = 0x0C,
+        Configuration = 0x0D,
+        RestoreFactoryDefaults = 0x0E,
+        RestoreFactoryDefaultsAck = 0x0F,
+        GoToMeasurement = 0x10,
+        GoToMeasurementAck = 0x11,
+        ReqFwRev = 0x12,
+        FirmwareRev = 0x13,
+        SetBaudrate = 0x18,
+        SetBaudrateAck = 0x19,
+        ReqProductCode = 0x1C,
+        ProductCode = 0x1D,
+        ReqHardwareVersion = 0x1E,
+        HardwareVersion = 0x1F,
+        SetProcessingFlags = 0x20,
+        SetProcessingFlagsAck = 0x21,
+        SetNoRotation = 0x22,
+        SetNoRotationAck = 0x23,
+        RunSelfTest = 0x24,
+        SelfTestResults = 0x25,
+        SetSyncSettings = 0x2C,
+        SetSyncSettingsAck = 0x2D,
+        GoToConfig = 0x30,
+        GoToConfigAck = 0x31,
+        /// Legacy MTData
+        MTData = 0x32,
+        ReqData = 0x34,
+        MTData2 = 0x36,
+        WakeUp = 0x3E,
+        WakeUpAck = 0x3F,
+        Reset = 0x40,
+        ResetAck = 0x41,
+        Error = 0x42,
+        Warning = 0x43,
+        SetOptionFlags = 0x48,
+        SetOptionFlagsAck = 0x49,
+        SetUtcTime = 0x60,
+        SetUtcTimeAck = 0x61,
+        ReqAvailableFilterProfiles = 0x62,
+        AvailableFilterProfiles = 0x63,
+        SetFilterProfile = 0x64,
+        SetFilterProfileAck = 0x65,
+        SetGnssLeverArm = 0x68,
+        SetGnssLeverArmAck = 0x69,
+        SetMagneticDeclination = 0x6A,
+        SetMagneticDeclinationAck = 0x6B,
+        SetLatLonAlt = 0x6E,
+        SetLatLonAltAck = 0x6F,
+        IccCommand = 0x74,
+        IccCommandAck = 0x75,
+        SetGnssPlatform = 0x76,
+        SetGnssPlatformAck = 0x77,
+        /// Legacy heading offset
+        SetHeading = 0x82,
+        SetHeadingAck = 0x83,
+        SetLocationId = 0x84,
+        SetLocationIdAck = 0x85,
+        SetExtOutputMode = 0x86,
+        SetExtOutputModeAck = 0x87,
+        StoreFilterState = 0x8A,
+        StoreFilterStateAck = 0x8B,
+        SetPortConfig = 0x8C,
+        SetPortConfigAck = 0x8D,
+        SetStringOutputType = 0x8E,
+        SetStringOutputTypeAck = 0x8F,
+        /// Legacy extended memory test
+        ReqEmts = 0x90,
+        EmtsData = 0x91,
+        ResetOrientation = 0xA4,
+        ResetOrientationAck = 0xA5,
+        ReqGpsStatus = 0xA6,
+        GpsStatus = 0xA7,
+        AdjustUtcTime = 0xA8,
+        AdjustUtcTimeAck = 0xA9,
+        ReqGnssReceiverSettings = 0xAC,
+        ReqGnssReceiverSettingsAck = 0xAD,
+        SetOutputConfiguration = 0xC0,
+        SetOutputConfigurationAck = 0xC1,
+        SetOutputMode = 0xD0,
+        SetOutputModeAck = 0xD1,
+        SetOutputSettings = 0xD2,
+        SetOutputSettingsAck = 0xD3,
+        SetOutputSkipFactor = 0xD4,
+        SetOutputSkipFactorAck = 0xD5,
+        SetInitialHeading = 0xD6,
+        SetInitialHeadingAck = 0xD7,
+        SetErrorMode = 0xDA,
+        SetErrorModeAck = 0xDB,
+        SetTransmitDelay = 0xDC,
+        SetTransmitDelayAck = 0xDD,
+        ForwardGnssData = 0xE2,
+        ForwardGnssDataAck = 0xE3,
+        SetCanConfig = 0xE6,
+        SetCanConfigAck = 0xE7,
+        SetCanOutputConfig = 0xE8,
+        SetCanOutputConfigAck = 0xE9,
+        SetAlignmentRotation = 0xEC,
+        SetAlignmentRotationAck = 0xED,
+    }
+}
+
+impl KnownMessageId {
+    /// Returns the identifier of the response the device sends for this message,
+    /// or None if the message is itself a response or is sent unsolicited
+    pub fn ack(&self) -> Option<KnownMessageId> {
+        match self {
+            KnownMessageId::ReqData => Some(KnownMessageId::MTData2),
+            KnownMessageId::Error
+            | KnownMessageId::Warning
+            | KnownMessageId::MTData
+            | KnownMessageId::MTData2
+            | KnownMessageId::Unknown(_) => None,
+            id => {
+                let raw = id.into_inner();
+                if raw % 2 == 0 {
+                    Some(KnownMessageId::from(raw + 1))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl From<MessageId> for KnownMessageId {
+    fn from(id: MessageId) -> Self {
+        KnownMessageId::from(id.0)
+    }
+}
+
+impl From<KnownMessageId> for MessageId {
+    fn from(id: KnownMessageId) -> Self {
+        MessageId(id.into_inner())
+    }
+}
+
+impl fmt::Display for KnownMessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnownMessageId::Unknown(id) => write!(f, "MsgId(0x{:X})", id),
+            id => fmt::Debug::fmt(id, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::MessageExt;
+    use crate::messages::*;
+    use pretty_assertions::assert_eq;
+
+    macro_rules! assert_msg_id {
+        ($($msg:ty => $id:ident),+ $(,)?) => {
+            $(
+                assert_eq!(
+                    KnownMessageId::from(<$msg>::MSG_ID),
+                    KnownMessageId::$id,
+                    stringify!($msg)
+                );
+            )+
+        };
+    }
+
+    #[test]
+    fn messages_match_catalog() {
+        assert_msg_id!(
+            ReqDid => ReqDid,
+            DeviceId => DeviceId,
+            RestoreFactoryDefaults => RestoreFactoryDefaults,
+            RestoreFactoryDefaultsAck => RestoreFactoryDefaultsAck,
+            GoToMeasurement => GoToMeasurement,
+            GoToMeasurementAck => GoToMeasurementAck,
+            ReqFwRev => ReqFwRev,
+            FirmwareRev => FirmwareRev,
+            ReqBaudrate => SetBaudrate,
+            ReqBaudrateAck => SetBaudrateAck,
+            SetBaudrate => SetBaudrate,
+            SetBaudrateAck => SetBaudrateAck,
+            ReqProductCode => ReqProductCode,
+            ProductCode<'static> => ProductCode,
+            ReqHardwareVersion => ReqHardwareVersion,
+            HardwareVersion => HardwareVersion,
+            SetNoRotation => SetNoRotation,
+            SetNoRotationAck => SetNoRotationAck,
+            RunSelfTest => RunSelfTest,
+            SelfTestResults => SelfTestResults,
+            ReqSyncSettings => SetSyncSettings,
+            SetSyncSettings<[crate::wire::SyncSetting; 0]> => SetSyncSettings,
+            SetSyncSettingsAck<'static> => SetSyncSettingsAck,
+            GoToConfig => GoToConfig,
+            GoToConfigAck => GoToConfigAck,
+            MTDataLegacy<'static> => MTData,
+            ReqData => ReqData,
+            MTData2<'static> => MTData2,
+            WakeUp => WakeUp,
+            WakeUpAck => WakeUpAck,
+            Reset => Reset,
+            ResetAck => ResetAck,
+            Error<'static> => Error,
+            Warning<'static> => Warning,
+            ReqOptionFlags => SetOptionFlags,
+            ReqOptionFlagsAck => SetOptionFlagsAck,
+            SetOptionFlags => SetOptionFlags,
+            SetOptionFlagsAck => SetOptionFlagsAck,
+            ReqUtcTime => SetUtcTime,
+            ReqUtcTimeAck => SetUtcTimeAck,
+            SetUtcTime => SetUtcTime,
+            SetUtcTimeAck => SetUtcTimeAck,
+            ReqAvailableFilterProfiles => ReqAvailableFilterProfiles,
+            AvailableFilterProfiles<'static> => AvailableFilterProfiles,
+            ReqFilterProfile => SetFilterProfile,
+            ReqFilterProfileAck => SetFilterProfileAck,
+            SetFilterProfile => SetFilterProfile,
+            SetFilterProfileAck => SetFilterProfileAck,
+            SetGnssLeverArm => SetGnssLeverArm,
+            SetGnssLeverArmAck => SetGnssLeverArmAck,
+            SetLatLonAlt => SetLatLonAlt,
+            SetLatLonAltAck => SetLatLonAltAck,
+            IccCommand => IccCommand,
+            IccCommandAck => IccCommandAck,
+            ReqGnssPlatform => SetGnssPlatform,
+            ReqGnssPlatformAck => SetGnssPlatformAck,
+            SetGnssPlatform => SetGnssPlatform,
+            SetGnssPlatformAck => SetGnssPlatformAck,
+            ReqLocationId => SetLocationId,
+            ReqLocationIdAck => SetLocationIdAck,
+            SetLocationId => SetLocationId,
+            SetLocationIdAck => SetLocationIdAck,
+            StoreFilterState => StoreFilterState,
+            StoreFilterStateAck => StoreFilterStateAck,
+            ReqPortConfig => SetPortConfig,
+            ReqPortConfigAck<'static> => SetPortConfigAck,
+            SetPortConfig<[PortConfig; 0]> => SetPortConfig,
+            SetPortConfigAck => SetPortConfigAck,
+            ResetOrientation => ResetOrientation,
+            ResetOrientationAck => ResetOrientationAck,
+            ReqGnssReceiverSettings => ReqGnssReceiverSettings,
+            ReqGnssReceiverSettingsAck => ReqGnssReceiverSettingsAck,
+            ReqOutputConfiguration => SetOutputConfiguration,
+            ReqOutputConfigurationAck<'static> => SetOutputConfigurationAck,
+            SetOutputConfiguration<[crate::wire::OutputConfiguration; 0]> => SetOutputConfiguration,
+            ReqOutputMode => SetOutputMode,
+            ReqOutputModeAck => SetOutputModeAck,
+            SetOutputMode => SetOutputMode,
+            SetOutputModeAck => SetOutputModeAck,
+            ReqOutputSettings => SetOutputSettings,
+            ReqOutputSettingsAck => SetOutputSettingsAck,
+            SetOutputSettings => SetOutputSettings,
+            SetOutputSettingsAck => SetOutputSettingsAck,
+            ReqCanConfig => SetCanConfig,
+            ReqCanConfigAck => SetCanConfigAck,
+            SetCanConfig => SetCanConfig,
+            SetCanConfigAck => SetCanConfigAck,
+            ReqCanOutputConfig => SetCanOutputConfig,
+            ReqCanOutputConfigAck<'static> => SetCanOutputConfigAck,
+            SetCanOutputConfig<[CanOutputConfiguration; 0]> => SetCanOutputConfig,
+            ReqAlignmentRotation => SetAlignmentRotation,
+            ReqAlignmentRotationAck => SetAlignmentRotationAck,
+            SetAlignmentRotation => SetAlignmentRotation,
+            SetAlignmentRotationAck => SetAlignmentRotationAck,
+        );
+    }
+
+    #[test]
+    fn ack() {
+        assert_eq!(
+            KnownMessageId::GoToConfig.ack(),
+            Some(KnownMessageId::GoToConfigAck)
+        );
+        assert_eq!(KnownMessageId::ReqDid.ack(), Some(KnownMessageId::DeviceId));
+        assert_eq!(KnownMessageId::ReqData.ack(), Some(KnownMessageId::MTData2));
+        assert_eq!(KnownMessageId::GoToConfigAck.ack(), None);
+        assert_eq!(KnownMessageId::Error.ack(), None);
+        assert_eq!(KnownMessageId::Unknown(0x02).ack(), None);
+        assert_eq!(
+            KnownMessageId::ReqDataLength.ack(),
+            Some(KnownMessageId::DataLength)
+        );
+        assert_eq!(
+            KnownMessageId::ForwardGnssData.ack(),
+            Some(KnownMessageId::ForwardGnssDataAck)
+        );
+        for raw in 0..=u8::MAX {
+            let id = KnownMessageId::from(raw);
+            if let Some(ack) = id.ack() {
+                assert!(!matches!(ack, KnownMessageId::Unknown(_)), "{:?}", id);
+            }
+        }
+    }
+}
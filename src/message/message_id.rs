@@ -1,3 +1,5 @@
+use crate::message::KnownMessageId;
+use core::fmt;
 use static_assertions::assert_eq_size;
 
 assert_eq_size!(MessageId, u8);
@@ -23,3 +25,9 @@ impl From<MessageId> for u8 {
         id.0
     }
 }
+
+impl fmt::Display for MessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&KnownMessageId::from(*self), f)
+    }
+}
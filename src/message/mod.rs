@@ -1,10 +1,12 @@
 mod bus_id;
 mod frame;
+mod known_message_id;
 mod message_id;
 mod payload_length;
 
 pub use bus_id::BusId;
 pub use frame::{Frame, FrameError};
+pub use known_message_id::KnownMessageId;
 pub use message_id::MessageId;
 pub use payload_length::PayloadLength;
 
@@ -19,8 +21,11 @@ pub trait MessageExt {
 }
 
 pub trait MessageEncode: MessageExt {
-    // TODO - some sort of PayloadLength or size hint
-    //
+    /// The largest payload length of the message in bytes, a fixed size message
+    /// always encodes exactly this many bytes.
+    /// Use it with [`Frame::buffer_len`] to size buffers at compile time.
+    const MAX_PAYLOAD_LEN: usize;
+
     // caller sets
     //   preamble, bus_id, checksum
     //
@@ -33,6 +38,17 @@ pub trait MessageEncode: MessageExt {
     }
 
     fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError>;
+
+    /// Return the payload length of this message in bytes,
+    /// variable size messages must override the default
+    fn payload_len(&self) -> usize {
+        Self::MAX_PAYLOAD_LEN
+    }
+
+    /// Return the size of the complete encoded frame, including the header and checksum
+    fn encoded_len(&self) -> usize {
+        Frame::<&[u8]>::buffer_len(self.payload_len())
+    }
 }
 
 pub trait MessageDecode<'buf>: MessageExt {
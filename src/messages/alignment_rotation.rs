@@ -0,0 +1,239 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    wire::WireError,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+enum_with_unknown! {
+    /// Selects which alignment rotation a message refers to
+    pub enum AlignmentFrame(u8) {
+        /// RotSensor, rotates the sensor frame (S) to the object frame (O)
+        Sensor = 0,
+        /// RotLocal, rotates the local frame (L) to the local earth frame (L')
+        Local = 1,
+    }
+}
+
+/// An alignment rotation quaternion (q0, q1, q2, q3) for either the
+/// sensor or local frame
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct AlignmentRotation {
+    pub frame: AlignmentFrame,
+    pub quaternion: [f32; 4],
+}
+
+impl fmt::Display for AlignmentRotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?}, Q0({:.3}), Q1({:.3}), Q2({:.3}), Q3({:.3})",
+            self.frame,
+            self.quaternion[0],
+            self.quaternion[1],
+            self.quaternion[2],
+            self.quaternion[3]
+        )
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const FRAME: usize = 0;
+    pub const Q0: Field = 1..5;
+    pub const Q1: Field = 5..9;
+    pub const Q2: Field = 9..13;
+    pub const Q3: Field = 13..17;
+}
+
+impl AlignmentRotation {
+    pub const WIRE_SIZE: usize = 17;
+
+    /// The identity rotation, no alignment
+    pub fn identity(frame: AlignmentFrame) -> Self {
+        AlignmentRotation {
+            frame,
+            quaternion: [1.0, 0.0, 0.0, 0.0],
+        }
+    }
+
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+        if bytes.len() < Self::WIRE_SIZE {
+            Err(WireError::MissingBytes)
+        } else {
+            Ok(AlignmentRotation {
+                frame: bytes[field::FRAME].into(),
+                quaternion: [
+                    BigEndian::read_f32(&bytes[field::Q0]),
+                    BigEndian::read_f32(&bytes[field::Q1]),
+                    BigEndian::read_f32(&bytes[field::Q2]),
+                    BigEndian::read_f32(&bytes[field::Q3]),
+                ],
+            })
+        }
+    }
+
+    pub fn to_be_slice(&self, bytes: &mut [u8]) -> Result<(), WireError> {
+        if bytes.len() < Self::WIRE_SIZE {
+            Err(WireError::MissingBytes)
+        } else {
+            bytes[field::FRAME] = self.frame.into();
+            BigEndian::write_f32(&mut bytes[field::Q0], self.quaternion[0]);
+            BigEndian::write_f32(&mut bytes[field::Q1], self.quaternion[1]);
+            BigEndian::write_f32(&mut bytes[field::Q2], self.quaternion[2]);
+            BigEndian::write_f32(&mut bytes[field::Q3], self.quaternion[3]);
+            Ok(())
+        }
+    }
+}
+
+/// Request the alignment rotation of the sensor or local frame
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqAlignmentRotation {
+    pub frame: AlignmentFrame,
+}
+
+impl MessageExt for ReqAlignmentRotation {
+    const MSG_ID: MessageId = MessageId::new(0xEC);
+}
+
+impl MessageEncode for ReqAlignmentRotation {
+    const MAX_PAYLOAD_LEN: usize = 1;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(1));
+        frame.check_payload_length()?;
+        frame.payload_mut()?[field::FRAME] = self.frame.into();
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqAlignmentRotation {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.is_empty() {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(ReqAlignmentRotation {
+                frame: payload[field::FRAME].into(),
+            })
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct ReqAlignmentRotationAck(pub AlignmentRotation);
+
+impl MessageExt for ReqAlignmentRotationAck {
+    const MSG_ID: MessageId = MessageId::new(0xED);
+}
+
+impl MessageEncode for ReqAlignmentRotationAck {
+    const MAX_PAYLOAD_LEN: usize = AlignmentRotation::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(AlignmentRotation::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        self.0
+            .to_be_slice(frame.payload_mut()?)
+            .map_err(|_| FrameError::InvalidMessagePayload)
+    }
+}
+
+impl MessageDecode<'_> for ReqAlignmentRotationAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        AlignmentRotation::from_be_slice(payload)
+            .map(ReqAlignmentRotationAck)
+            .map_err(|_| FrameError::InvalidMessagePayload)
+    }
+}
+
+/// Set the alignment rotation of the sensor or local frame, only valid in Config State.
+/// The new setting is applied after a reset of the device.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct SetAlignmentRotation(pub AlignmentRotation);
+
+impl MessageExt for SetAlignmentRotation {
+    const MSG_ID: MessageId = MessageId::new(0xEC);
+}
+
+impl MessageEncode for SetAlignmentRotation {
+    const MAX_PAYLOAD_LEN: usize = AlignmentRotation::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(AlignmentRotation::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        self.0
+            .to_be_slice(frame.payload_mut()?)
+            .map_err(|_| FrameError::InvalidMessagePayload)
+    }
+}
+
+impl MessageDecode<'_> for SetAlignmentRotation {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        AlignmentRotation::from_be_slice(payload)
+            .map(SetAlignmentRotation)
+            .map_err(|_| FrameError::InvalidMessagePayload)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetAlignmentRotationAck;
+
+impl MessageExt for SetAlignmentRotationAck {
+    const MSG_ID: MessageId = MessageId::new(0xED);
+}
+
+impl MessageEncode for SetAlignmentRotationAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetAlignmentRotationAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetAlignmentRotationAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 22] = [
+        0xFA, 0xFF, 0xEC, 0x11,
+        0x00,
+        0x3F, 0x35, 0x04, 0xF3,
+        0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x3F, 0x35, 0x04, 0xF3,
+        0x00,
+    ];
+
+    #[test]
+    fn round_trip() {
+        let f = Frame::new_unchecked(&MSG[..]);
+        let msg = SetAlignmentRotation::decode(&f).unwrap();
+        assert_eq!(msg.0.frame, AlignmentFrame::Sensor);
+        assert_relative_eq!(msg.0.quaternion[0], core::f32::consts::FRAC_1_SQRT_2);
+        assert_relative_eq!(msg.0.quaternion[1], 0.0);
+        assert_relative_eq!(msg.0.quaternion[2], 0.0);
+        assert_relative_eq!(msg.0.quaternion[3], core::f32::consts::FRAC_1_SQRT_2);
+
+        let mut bytes = [0_u8; 22];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        msg.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..21], &MSG[2..21]);
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{
+    message::{Frame, FrameError, MessageDecode, MessageExt, MessageId},
+    messages::*,
+};
+
+/// Generates the [`AnyMessage`] variants and the dispatcher.
+///
+/// Messages that share an identifier are disambiguated by a pattern on the
+/// payload length, the first matching entry is used.
+///
+/// The list is maintained by hand, new messages must be added here as well.
+/// The `catalog_coverage` test fails for any [`KnownMessageId`](crate::message::KnownMessageId)
+/// that is neither listed here nor in its documented exceptions.
+macro_rules! any_message {
+    ($($variant:ident($ty:ty) $([len: $len:pat])?),+ $(,)?) => {
+        /// Any of the supported messages, see [`AnyMessage::decode`].
+        ///
+        /// The list type Set* messages that are only sent to the device,
+        /// [`SetSyncSettings`], [`SetPortConfig`], [`SetOutputConfiguration`] and
+        /// [`SetCanOutputConfig`], are encode-only and decode as [`AnyMessage::Unknown`]
+        /// when they carry a payload.
+        #[derive(Debug, Clone)]
+        pub enum AnyMessage<'a> {
+            $($variant($ty),)+
+            /// A frame that is not one of the supported messages
+            Unknown(Frame<&'a [u8]>),
+        }
+
+        impl<'a> AnyMessage<'a> {
+            /// Decode a frame into the message matching its identifier and payload length
+            pub fn decode(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+                let id = frame.message_id();
+                #[allow(unused_variables)]
+                let len = frame.payload()?.len();
+                $(
+                    if id == <$ty>::MSG_ID $(&& matches!(len, $len))? {
+                        return <$ty as MessageDecode<'a>>::decode_frame(frame)
+                            .map(AnyMessage::$variant);
+                    }
+                )+
+                Ok(AnyMessage::Unknown(frame.clone()))
+            }
+
+            pub fn message_id(&self) -> MessageId {
+                match self {
+                    $(AnyMessage::$variant(_) => <$ty>::MSG_ID,)+
+                    AnyMessage::Unknown(f) => f.message_id(),
+                }
+            }
+        }
+    };
+}
+
+any_message! {
+    ReqDid(ReqDid),
+    DeviceId(DeviceId),
+    RestoreFactoryDefaults(RestoreFactoryDefaults),
+    RestoreFactoryDefaultsAck(RestoreFactoryDefaultsAck),
+    GoToMeasurement(GoToMeasurement),
+    GoToMeasurementAck(GoToMeasurementAck),
+    ReqFwRev(ReqFwRev),
+    FirmwareRev(FirmwareRev),
+    ReqBaudrate(ReqBaudrate) [len: 0],
+    SetBaudrate(SetBaudrate),
+    SetBaudrateAck(SetBaudrateAck) [len: 0],
+    ReqBaudrateAck(ReqBaudrateAck),
+    ReqProductCode(ReqProductCode),
+    ProductCode(ProductCode<'a>),
+    ReqHardwareVersion(ReqHardwareVersion),
+    HardwareVersion(HardwareVersion),
+    SetNoRotation(SetNoRotation),
+    SetNoRotationAck(SetNoRotationAck),
+    RunSelfTest(RunSelfTest),
+    SelfTestResults(SelfTestResults),
+    ReqSyncSettings(ReqSyncSettings) [len: 0],
+    SetSyncSettingsAck(SetSyncSettingsAck<'a>),
+    GoToConfig(GoToConfig),
+    GoToConfigAck(GoToConfigAck),
+    MTDataLegacy(MTDataLegacy<'a>),
+    ReqData(ReqData),
+    MTData2(MTData2<'a>),
+    WakeUp(WakeUp),
+    WakeUpAck(WakeUpAck),
+    Reset(Reset),
+    ResetAck(ResetAck),
+    Error(Error<'a>),
+    Warning(Warning<'a>),
+    ReqOptionFlags(ReqOptionFlags) [len: 0],
+    SetOptionFlags(SetOptionFlags),
+    SetOptionFlagsAck(SetOptionFlagsAck) [len: 0],
+    ReqOptionFlagsAck(ReqOptionFlagsAck),
+    ReqUtcTime(ReqUtcTime) [len: 0],
+    SetUtcTime(SetUtcTime),
+    SetUtcTimeAck(SetUtcTimeAck) [len: 0],
+    ReqUtcTimeAck(ReqUtcTimeAck),
+    ReqAvailableFilterProfiles(ReqAvailableFilterProfiles),
+    AvailableFilterProfiles(AvailableFilterProfiles<'a>),
+    ReqFilterProfile(ReqFilterProfile) [len: 0],
+    SetFilterProfile(SetFilterProfile),
+    SetFilterProfileAck(SetFilterProfileAck) [len: 0],
+    ReqFilterProfileAck(ReqFilterProfileAck),
+    SetGnssLeverArm(SetGnssLeverArm),
+    SetGnssLeverArmAck(SetGnssLeverArmAck),
+    SetLatLonAlt(SetLatLonAlt),
+    SetLatLonAltAck(SetLatLonAltAck),
+    IccCommand(IccCommand),
+    IccCommandAck(IccCommandAck),
+    ReqGnssPlatform(ReqGnssPlatform) [len: 0],
+    SetGnssPlatform(SetGnssPlatform),
+    SetGnssPlatformAck(SetGnssPlatformAck) [len: 0],
+    ReqGnssPlatformAck(ReqGnssPlatformAck),
+    ReqLocationId(ReqLocationId) [len: 0],
+    SetLocationId(SetLocationId),
+    SetLocationIdAck(SetLocationIdAck) [len: 0],
+    ReqLocationIdAck(ReqLocationIdAck),
+    StoreFilterState(StoreFilterState),
+    StoreFilterStateAck(StoreFilterStateAck),
+    ReqPortConfig(ReqPortConfig) [len: 0],
+    SetPortConfigAck(SetPortConfigAck) [len: 0],
+    ReqPortConfigAck(ReqPortConfigAck<'a>),
+    ResetOrientation(ResetOrientation),
+    ResetOrientationAck(ResetOrientationAck),
+    ReqGnssReceiverSettings(ReqGnssReceiverSettings),
+    ReqGnssReceiverSettingsAck(ReqGnssReceiverSettingsAck),
+    ReqOutputConfiguration(ReqOutputConfiguration) [len: 0],
+    SetOutputConfigurationAck(SetOutputConfigurationAck<'a>),
+    ReqOutputMode(ReqOutputMode) [len: 0],
+    SetOutputMode(SetOutputMode),
+    SetOutputModeAck(SetOutputModeAck) [len: 0],
+    ReqOutputModeAck(ReqOutputModeAck),
+    ReqOutputSettings(ReqOutputSettings) [len: 0],
+    SetOutputSettings(SetOutputSettings),
+    SetOutputSettingsAck(SetOutputSettingsAck) [len: 0],
+    ReqOutputSettingsAck(ReqOutputSettingsAck),
+    ReqCanConfig(ReqCanConfig) [len: 0],
+    SetCanConfig(SetCanConfig),
+    SetCanConfigAck(SetCanConfigAck) [len: 0],
+    ReqCanConfigAck(ReqCanConfigAck),
+    ReqCanOutputConfig(ReqCanOutputConfig) [len: 0],
+    SetCanOutputConfigAck(SetCanOutputConfigAck<'a>),
+    ReqAlignmentRotation(ReqAlignmentRotation) [len: 1],
+    SetAlignmentRotation(SetAlignmentRotation),
+    SetAlignmentRotationAck(SetAlignmentRotationAck) [len: 0],
+    ReqAlignmentRotationAck(ReqAlignmentRotationAck),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::KnownMessageId;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn decode_shared_ids() {
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x18, 0x00, 0xE9][..]);
+        assert!(matches!(
+            AnyMessage::decode(&f),
+            Ok(AnyMessage::ReqBaudrate(_))
+        ));
+
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x19, 0x01, 0x02, 0xE5][..]);
+        assert!(matches!(
+            AnyMessage::decode(&f),
+            Ok(AnyMessage::ReqBaudrateAck(ReqBaudrateAck(
+                BaudRate::Baud115200
+            )))
+        ));
+
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x19, 0x00, 0xE8][..]);
+        assert!(matches!(
+            AnyMessage::decode(&f),
+            Ok(AnyMessage::SetBaudrateAck(_))
+        ));
+    }
+
+    #[test]
+    fn decode_unknown() {
+        let bytes = [0xFA, 0xFF, 0x02, 0x00, 0xFF];
+        let f = Frame::new_unchecked(&bytes[..]);
+        let msg = AnyMessage::decode(&f).unwrap();
+        assert!(matches!(msg, AnyMessage::Unknown(_)));
+        assert_eq!(msg.message_id(), MessageId(0x02));
+    }
+
+    #[test]
+    fn catalog_coverage() {
+        // Catalog entries without a message type in this crate
+        const UNSUPPORTED: [KnownMessageId; 32] = [
+            KnownMessageId::SetPeriod,
+            KnownMessageId::SetPeriodAck,
+            KnownMessageId::ReqConfiguration,
+            KnownMessageId::Configuration,
+            KnownMessageId::SetProcessingFlags,
+            KnownMessageId::SetProcessingFlagsAck,
+            KnownMessageId::SetMagneticDeclination,
+            KnownMessageId::SetMagneticDeclinationAck,
+            KnownMessageId::SetExtOutputMode,
+            KnownMessageId::SetExtOutputModeAck,
+            KnownMessageId::SetStringOutputType,
+            KnownMessageId::SetStringOutputTypeAck,
+            KnownMessageId::ReqGpsStatus,
+            KnownMessageId::GpsStatus,
+            KnownMessageId::SetOutputSkipFactor,
+            KnownMessageId::SetOutputSkipFactorAck,
+            KnownMessageId::SetErrorMode,
+            KnownMessageId::SetErrorModeAck,
+            KnownMessageId::SetTransmitDelay,
+            KnownMessageId::SetTransmitDelayAck,
+            KnownMessageId::ReqDataLength,
+            KnownMessageId::DataLength,
+            KnownMessageId::SetHeading,
+            KnownMessageId::SetHeadingAck,
+            KnownMessageId::ReqEmts,
+            KnownMessageId::EmtsData,
+            KnownMessageId::AdjustUtcTime,
+            KnownMessageId::AdjustUtcTimeAck,
+            KnownMessageId::SetInitialHeading,
+            KnownMessageId::SetInitialHeadingAck,
+            KnownMessageId::ForwardGnssData,
+            KnownMessageId::ForwardGnssDataAck,
+        ];
+        // Encode-only list messages, only their empty Req* form is decoded
+        const ENCODE_ONLY: [KnownMessageId; 4] = [
+            KnownMessageId::SetSyncSettings,
+            KnownMessageId::SetPortConfig,
+            KnownMessageId::SetOutputConfiguration,
+            KnownMessageId::SetCanOutputConfig,
+        ];
+
+        let mut bytes = [0_u8; 69];
+        for raw in 0..=u8::MAX {
+            let id = KnownMessageId::from(raw);
+            if matches!(id, KnownMessageId::Unknown(_)) {
+                continue;
+            }
+            for len in [0, 64] {
+                bytes[2] = raw;
+                bytes[3] = len as u8;
+                let f = Frame::new_unchecked(&bytes[..len + 5]);
+                let unknown = matches!(AnyMessage::decode(&f), Ok(AnyMessage::Unknown(_)));
+                let expected = UNSUPPORTED.contains(&id) || (len != 0 && ENCODE_ONLY.contains(&id));
+                assert_eq!(unknown, expected, "{} with {} payload bytes", id, len);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_payload() {
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x49, 0x01, 0x00, 0xB7][..]);
+        assert_eq!(
+            AnyMessage::decode(&f).unwrap_err(),
+            FrameError::InvalidMessagePayload
+        );
+    }
+}
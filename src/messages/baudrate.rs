@@ -0,0 +1,230 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use core::fmt;
+
+enum_with_unknown! {
+    /// Baud rate codes used by the device
+    pub enum BaudRate(u8) {
+        Baud4800 = 0x0B,
+        Baud9600 = 0x09,
+        Baud14400 = 0x08,
+        Baud19200 = 0x07,
+        Baud28800 = 0x06,
+        Baud38400 = 0x05,
+        Baud57600 = 0x04,
+        Baud76800 = 0x03,
+        Baud115200 = 0x02,
+        Baud230400 = 0x01,
+        Baud460800 = 0x00,
+        Baud921600 = 0x0A,
+        /// 921600 baud, code used by older firmware
+        Baud921600Legacy = 0x80,
+        Baud2000k = 0x0C,
+        Baud4000k = 0x0D,
+        Baud3500k = 0x0E,
+    }
+}
+
+impl BaudRate {
+    /// Return the baud rate in bits per second
+    pub fn bits_per_second(&self) -> Option<u32> {
+        Some(match self {
+            BaudRate::Baud4800 => 4_800,
+            BaudRate::Baud9600 => 9_600,
+            BaudRate::Baud14400 => 14_400,
+            BaudRate::Baud19200 => 19_200,
+            BaudRate::Baud28800 => 28_800,
+            BaudRate::Baud38400 => 38_400,
+            BaudRate::Baud57600 => 57_600,
+            BaudRate::Baud76800 => 76_800,
+            BaudRate::Baud115200 => 115_200,
+            BaudRate::Baud230400 => 230_400,
+            BaudRate::Baud460800 => 460_800,
+            BaudRate::Baud921600 | BaudRate::Baud921600Legacy => 921_600,
+            BaudRate::Baud2000k => 2_000_000,
+            BaudRate::Baud4000k => 4_000_000,
+            BaudRate::Baud3500k => 3_500_000,
+            BaudRate::Unknown(_) => return None,
+        })
+    }
+
+    /// Return the baud rate code for a rate in bits per second
+    pub fn from_bits_per_second(bps: u32) -> Option<Self> {
+        Some(match bps {
+            4_800 => BaudRate::Baud4800,
+            9_600 => BaudRate::Baud9600,
+            14_400 => BaudRate::Baud14400,
+            19_200 => BaudRate::Baud19200,
+            28_800 => BaudRate::Baud28800,
+            38_400 => BaudRate::Baud38400,
+            57_600 => BaudRate::Baud57600,
+            76_800 => BaudRate::Baud76800,
+            115_200 => BaudRate::Baud115200,
+            230_400 => BaudRate::Baud230400,
+            460_800 => BaudRate::Baud460800,
+            921_600 => BaudRate::Baud921600,
+            2_000_000 => BaudRate::Baud2000k,
+            4_000_000 => BaudRate::Baud4000k,
+            3_500_000 => BaudRate::Baud3500k,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for BaudRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bits_per_second() {
+            Some(bps) => write!(f, "{}", bps),
+            None => write!(f, "Unknown(0x{:02X})", self.into_inner()),
+        }
+    }
+}
+
+mod field {
+    pub const BAUD_RATE: usize = 0;
+}
+
+/// Request the baud rate of the device's current port
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqBaudrate;
+
+impl MessageExt for ReqBaudrate {
+    const MSG_ID: MessageId = MessageId::new(0x18);
+}
+
+impl MessageEncode for ReqBaudrate {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqBaudrate {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqBaudrate)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqBaudrateAck(pub BaudRate);
+
+impl MessageExt for ReqBaudrateAck {
+    const MSG_ID: MessageId = MessageId::new(0x19);
+}
+
+impl MessageEncode for ReqBaudrateAck {
+    const MAX_PAYLOAD_LEN: usize = 1;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(1));
+        frame.check_payload_length()?;
+        frame.payload_mut()?[field::BAUD_RATE] = self.0.into();
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqBaudrateAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.is_empty() {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(ReqBaudrateAck(payload[field::BAUD_RATE].into()))
+        }
+    }
+}
+
+/// Change the baud rate of the device's current port.
+/// The new baud rate is applied after a reset of the device.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetBaudrate(pub BaudRate);
+
+impl MessageExt for SetBaudrate {
+    const MSG_ID: MessageId = MessageId::new(0x18);
+}
+
+impl MessageEncode for SetBaudrate {
+    const MAX_PAYLOAD_LEN: usize = 1;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(1));
+        frame.check_payload_length()?;
+        frame.payload_mut()?[field::BAUD_RATE] = self.0.into();
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetBaudrate {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.is_empty() {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(SetBaudrate(payload[field::BAUD_RATE].into()))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetBaudrateAck;
+
+impl MessageExt for SetBaudrateAck {
+    const MSG_ID: MessageId = MessageId::new(0x19);
+}
+
+impl MessageEncode for SetBaudrateAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetBaudrateAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetBaudrateAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bits_per_second() {
+        assert_eq!(BaudRate::Baud115200.bits_per_second(), Some(115_200));
+        assert_eq!(BaudRate::Baud921600Legacy.bits_per_second(), Some(921_600));
+        assert_eq!(BaudRate::Unknown(0x42).bits_per_second(), None);
+        assert_eq!(
+            BaudRate::from_bits_per_second(921_600),
+            Some(BaudRate::Baud921600)
+        );
+        assert_eq!(BaudRate::from_bits_per_second(1234), None);
+        for code in 0..=u8::MAX {
+            let b = BaudRate::from(code);
+            if let Some(bps) = b.bits_per_second() {
+                if b != BaudRate::Baud921600Legacy {
+                    assert_eq!(BaudRate::from_bits_per_second(bps), Some(b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = [0_u8; 6];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        SetBaudrate(BaudRate::Baud921600).encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..5], &[0x18, 0x01, 0x0A]);
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(
+            SetBaudrate::decode(&f),
+            Ok(SetBaudrate(BaudRate::Baud921600))
+        );
+    }
+}
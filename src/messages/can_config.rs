@@ -0,0 +1,226 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use bitfield::bitfield;
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+enum_with_unknown! {
+    /// CAN bus baud rate codes
+    pub enum CanBaudRate(u8) {
+        Baud1000k = 0x0C,
+        Baud800k = 0x0B,
+        Baud500k = 0x0A,
+        Baud250k = 0x00,
+        Baud125k = 0x01,
+        Baud100k = 0x02,
+        Baud83k3 = 0x03,
+        Baud62k5 = 0x04,
+        Baud50k = 0x05,
+        Baud33k3 = 0x06,
+        Baud20k = 0x07,
+        Baud10k = 0x08,
+        Baud5k = 0x09,
+    }
+}
+
+impl CanBaudRate {
+    /// Return the baud rate in bits per second
+    pub fn bits_per_second(&self) -> Option<u32> {
+        Some(match self {
+            CanBaudRate::Baud1000k => 1_000_000,
+            CanBaudRate::Baud800k => 800_000,
+            CanBaudRate::Baud500k => 500_000,
+            CanBaudRate::Baud250k => 250_000,
+            CanBaudRate::Baud125k => 125_000,
+            CanBaudRate::Baud100k => 100_000,
+            CanBaudRate::Baud83k3 => 83_333,
+            CanBaudRate::Baud62k5 => 62_500,
+            CanBaudRate::Baud50k => 50_000,
+            CanBaudRate::Baud33k3 => 33_333,
+            CanBaudRate::Baud20k => 20_000,
+            CanBaudRate::Baud10k => 10_000,
+            CanBaudRate::Baud5k => 5_000,
+            CanBaudRate::Unknown(_) => return None,
+        })
+    }
+}
+
+impl fmt::Display for CanBaudRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bits_per_second() {
+            Some(bps) => write!(f, "{}", bps),
+            None => write!(f, "Unknown(0x{:02X})", self.into_inner()),
+        }
+    }
+}
+
+bitfield! {
+    /// The 32bit CAN interface configuration
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+    #[repr(transparent)]
+    pub struct CanConfig(u32);
+    u8, baud_rate_code, set_baud_rate_code : 7, 0;
+    /// Enable the CAN interface
+    pub enabled, set_enabled : 8;
+}
+
+impl CanConfig {
+    pub const WIRE_SIZE: usize = 4;
+
+    pub fn new(baud_rate: CanBaudRate, enabled: bool) -> Self {
+        let mut c = CanConfig::default();
+        c.set_baud_rate(baud_rate);
+        c.set_enabled(enabled);
+        c
+    }
+
+    pub fn baud_rate(&self) -> CanBaudRate {
+        self.baud_rate_code().into()
+    }
+
+    pub fn set_baud_rate(&mut self, value: CanBaudRate) {
+        self.set_baud_rate_code(value.into())
+    }
+}
+
+impl fmt::Display for CanConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CanConfig(BaudRate({}), Enabled({}))",
+            self.baud_rate(),
+            self.enabled()
+        )
+    }
+}
+
+mod field {
+    use crate::wire::{field32, Field};
+
+    pub const CONFIG: Field = field32::F0;
+}
+
+fn encode_can_config(config: CanConfig, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+    frame.set_payload_length(PayloadLength::Standard(CanConfig::WIRE_SIZE as u8));
+    frame.check_payload_length()?;
+    BigEndian::write_u32(&mut frame.payload_mut()?[field::CONFIG], config.0);
+    Ok(())
+}
+
+fn decode_can_config(frame: &Frame<&[u8]>) -> Result<CanConfig, FrameError> {
+    let payload = frame.payload()?;
+    if payload.len() < CanConfig::WIRE_SIZE {
+        Err(FrameError::InvalidMessagePayload)
+    } else {
+        Ok(CanConfig(BigEndian::read_u32(&payload[field::CONFIG])))
+    }
+}
+
+/// Request the CAN interface configuration
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqCanConfig;
+
+impl MessageExt for ReqCanConfig {
+    const MSG_ID: MessageId = MessageId::new(0xE6);
+}
+
+impl MessageEncode for ReqCanConfig {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqCanConfig {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqCanConfig)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqCanConfigAck(pub CanConfig);
+
+impl MessageExt for ReqCanConfigAck {
+    const MSG_ID: MessageId = MessageId::new(0xE7);
+}
+
+impl MessageEncode for ReqCanConfigAck {
+    const MAX_PAYLOAD_LEN: usize = CanConfig::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_can_config(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for ReqCanConfigAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_can_config(frame).map(ReqCanConfigAck)
+    }
+}
+
+/// Set the CAN interface configuration, only valid in Config State
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetCanConfig(pub CanConfig);
+
+impl MessageExt for SetCanConfig {
+    const MSG_ID: MessageId = MessageId::new(0xE6);
+}
+
+impl MessageEncode for SetCanConfig {
+    const MAX_PAYLOAD_LEN: usize = CanConfig::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_can_config(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for SetCanConfig {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_can_config(frame).map(SetCanConfig)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetCanConfigAck;
+
+impl MessageExt for SetCanConfigAck {
+    const MSG_ID: MessageId = MessageId::new(0xE7);
+}
+
+impl MessageEncode for SetCanConfigAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetCanConfigAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetCanConfigAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = [0_u8; 9];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        let msg = SetCanConfig(CanConfig::new(CanBaudRate::Baud500k, true));
+        msg.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..8], &[0xE6, 0x04, 0x00, 0x00, 0x01, 0x0A]);
+        let f = Frame::new_unchecked(&bytes[..]);
+        let out = SetCanConfig::decode(&f).unwrap();
+        assert_eq!(out, msg);
+        assert_eq!(out.0.baud_rate(), CanBaudRate::Baud500k);
+        assert!(out.0.enabled());
+    }
+}
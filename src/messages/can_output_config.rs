@@ -0,0 +1,280 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    wire::{OutputFrequency, WireError},
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::cmp;
+
+enum_with_unknown! {
+    /// Identifies the data carried by a CAN message
+    pub enum CanDataIdentifier(u8) {
+        Error = 0x01,
+        Warning = 0x02,
+        SampleTime = 0x05,
+        GroupCounter = 0x06,
+        UtcTime = 0x07,
+        StatusWord = 0x11,
+        Quaternion = 0x21,
+        EulerAngles = 0x22,
+        RotationMatrix = 0x23,
+        DeltaV = 0x31,
+        RateOfTurn = 0x32,
+        DeltaQ = 0x33,
+        Acceleration = 0x34,
+        FreeAcceleration = 0x35,
+        MagneticField = 0x41,
+        Temperature = 0x51,
+        BaroPressure = 0x52,
+        RateOfTurnHr = 0x61,
+        AccelerationHr = 0x62,
+        LatLon = 0x71,
+        AltitudeEllipsoid = 0x72,
+        PositionEcefX = 0x73,
+        PositionEcefY = 0x74,
+        PositionEcefZ = 0x75,
+        Velocity = 0x76,
+        GnssReceiverStatus = 0x79,
+        GnssReceiverDop = 0x7A,
+    }
+}
+
+enum_with_unknown! {
+    /// The CAN identifier format
+    pub enum CanFrameFormat(u8) {
+        /// 11 bit identifier
+        Standard = 0,
+        /// 29 bit identifier
+        Extended = 1,
+    }
+}
+
+/// The output configuration of a single CAN message
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CanOutputConfiguration {
+    pub data_identifier: CanDataIdentifier,
+    pub frame_format: CanFrameFormat,
+    pub can_id: u32,
+    pub output_frequency: OutputFrequency,
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const DATA_IDENTIFIER: usize = 0;
+    pub const FRAME_FORMAT: usize = 1;
+    pub const CAN_ID: Field = 2..6;
+    pub const FREQ: Field = 6..8;
+}
+
+impl CanOutputConfiguration {
+    pub const WIRE_SIZE: usize = field::FREQ.end;
+
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, WireError> {
+        if bytes.len() < Self::WIRE_SIZE {
+            Err(WireError::MissingBytes)
+        } else {
+            Ok(Self::from_be_slice_unchecked(bytes))
+        }
+    }
+
+    /// Panics if `bytes` is shorter than `WIRE_SIZE`
+    fn from_be_slice_unchecked(bytes: &[u8]) -> Self {
+        CanOutputConfiguration {
+            data_identifier: bytes[field::DATA_IDENTIFIER].into(),
+            frame_format: bytes[field::FRAME_FORMAT].into(),
+            can_id: BigEndian::read_u32(&bytes[field::CAN_ID]),
+            output_frequency: BigEndian::read_u16(&bytes[field::FREQ]).into(),
+        }
+    }
+
+    pub fn to_be_slice(&self, bytes: &mut [u8]) -> Result<(), WireError> {
+        if bytes.len() < Self::WIRE_SIZE {
+            Err(WireError::MissingBytes)
+        } else {
+            bytes[field::DATA_IDENTIFIER] = self.data_identifier.into();
+            bytes[field::FRAME_FORMAT] = self.frame_format.into();
+            BigEndian::write_u32(&mut bytes[field::CAN_ID], self.can_id);
+            BigEndian::write_u16(&mut bytes[field::FREQ], self.output_frequency.into());
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CanOutputConfigurationIterator<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> CanOutputConfigurationIterator<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        CanOutputConfigurationIterator { buffer }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = CanOutputConfiguration> + 'a {
+        self.buffer
+            .chunks_exact(CanOutputConfiguration::WIRE_SIZE)
+            // unchecked ok, chunks are always WIRE_SIZE bytes in length
+            .map(CanOutputConfiguration::from_be_slice_unchecked)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TooManyCanOutputConfigurations;
+
+/// Request the CAN output configuration currently stored on the device.
+/// The device responds with a [`ReqCanOutputConfigAck`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqCanOutputConfig;
+
+impl MessageExt for ReqCanOutputConfig {
+    const MSG_ID: MessageId = MessageId::new(0xE8);
+}
+
+impl MessageEncode for ReqCanOutputConfig {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqCanOutputConfig {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqCanOutputConfig)
+    }
+}
+
+/// Set the CAN output configuration, only valid in Config State
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetCanOutputConfig<T: AsRef<[CanOutputConfiguration]>> {
+    settings: T,
+}
+
+impl<T: AsRef<[CanOutputConfiguration]>> MessageExt for SetCanOutputConfig<T> {
+    const MSG_ID: MessageId = MessageId::new(0xE8);
+}
+
+impl<T: AsRef<[CanOutputConfiguration]>> SetCanOutputConfig<T> {
+    /// The most settings that fit in a standard length payload
+    pub const MAX_SETTINGS: usize = 31;
+
+    pub fn new_unchecked(settings: T) -> Self {
+        SetCanOutputConfig { settings }
+    }
+
+    pub fn new(settings: T) -> Result<Self, TooManyCanOutputConfigurations> {
+        let m = Self::new_unchecked(settings);
+        m.check_len()?;
+        Ok(m)
+    }
+
+    pub fn check_len(&self) -> Result<(), TooManyCanOutputConfigurations> {
+        let len = self.settings.as_ref().len();
+        if len > Self::MAX_SETTINGS {
+            Err(TooManyCanOutputConfigurations)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: AsRef<[CanOutputConfiguration]>> MessageEncode for SetCanOutputConfig<T> {
+    const MAX_PAYLOAD_LEN: usize = Self::MAX_SETTINGS * CanOutputConfiguration::WIRE_SIZE;
+
+    fn payload_len(&self) -> usize {
+        cmp::min(Self::MAX_SETTINGS, self.settings.as_ref().len())
+            * CanOutputConfiguration::WIRE_SIZE
+    }
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        let settings = self.settings.as_ref();
+        let config_size = CanOutputConfiguration::WIRE_SIZE;
+        frame.set_payload_length(
+            PayloadLength::new(self.payload_len()).ok_or(FrameError::InvalidPayloadLength)?,
+        );
+        frame.check_payload_length()?;
+        for (src, dst) in settings
+            .iter()
+            .zip(frame.payload_mut()?.chunks_exact_mut(config_size))
+        {
+            src.to_be_slice(dst)
+                .map_err(|_| FrameError::InvalidMessagePayload)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetCanOutputConfigAck<'a>(pub CanOutputConfigurationIterator<'a>);
+
+impl<'a> MessageExt for SetCanOutputConfigAck<'a> {
+    const MSG_ID: MessageId = MessageId::new(0xE9);
+}
+
+impl<'a> MessageDecode<'a> for SetCanOutputConfigAck<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        Ok(SetCanOutputConfigAck(CanOutputConfigurationIterator::new(
+            payload,
+        )))
+    }
+}
+
+/// The acknowledgement of a [`ReqCanOutputConfig`] has the same layout as
+/// the acknowledgement of a [`SetCanOutputConfig`]
+pub type ReqCanOutputConfigAck<'a> = SetCanOutputConfigAck<'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static ACK_MSG: [u8; 21] = [
+        0xFA, 0xFF, 0xE9, 0x10,
+        0x21, 0x00, 0x00, 0x00, 0x00, 0x21, 0x00, 0x64,
+        0x34, 0x01, 0x18, 0xFF, 0x00, 0x34, 0x01, 0x90,
+        0x00,
+    ];
+
+    fn settings() -> [CanOutputConfiguration; 2] {
+        [
+            CanOutputConfiguration {
+                data_identifier: CanDataIdentifier::Quaternion,
+                frame_format: CanFrameFormat::Standard,
+                can_id: 0x21,
+                output_frequency: OutputFrequency(100),
+            },
+            CanOutputConfiguration {
+                data_identifier: CanDataIdentifier::Acceleration,
+                frame_format: CanFrameFormat::Extended,
+                can_id: 0x18FF_0034,
+                output_frequency: OutputFrequency(400),
+            },
+        ]
+    }
+
+    #[test]
+    fn encode() {
+        let settings = settings();
+        let msg = SetCanOutputConfig::new(&settings[..]).unwrap();
+        let mut bytes = [0_u8; 21];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        msg.encode(&mut f).unwrap();
+        assert_eq!(&bytes[3..20], &ACK_MSG[3..20]);
+    }
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new_unchecked(&ACK_MSG[..]);
+        let msg = ReqCanOutputConfigAck::decode(&f).unwrap();
+        let mut iter = msg.0.iter();
+        let expected = settings();
+        assert_eq!(iter.next(), Some(expected[0]));
+        assert_eq!(iter.next(), Some(expected[1]));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+/// Request the device identifier
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqDid;
+
+impl MessageExt for ReqDid {
+    const MSG_ID: MessageId = MessageId::new(0x00);
+}
+
+impl MessageEncode for ReqDid {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqDid {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqDid)
+    }
+}
+
+enum_with_unknown! {
+    /// The product family encoded in the device identifier
+    pub enum ProductFamily(u8) {
+        Mti1Series = 0x8,
+        Mti10Series = 0x6,
+        Mti100Series = 0x7,
+    }
+}
+
+/// The 32-bit device identifier, also printed on the device's label
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DeviceId(pub u32);
+
+impl DeviceId {
+    pub const WIRE_SIZE: usize = 4;
+
+    /// The product family, third hex digit of the identifier
+    pub fn product_family(&self) -> ProductFamily {
+        ProductFamily::from(((self.0 >> 20) & 0xF) as u8)
+    }
+
+    /// The product type within the family, second hex digit of the identifier
+    /// (i.e. 3 for an MTi-3, MTi-30 or MTi-300)
+    pub fn product_type(&self) -> u8 {
+        ((self.0 >> 24) & 0xF) as u8
+    }
+}
+
+impl fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DeviceId(0x{:08X})", self.0)
+    }
+}
+
+impl MessageExt for DeviceId {
+    const MSG_ID: MessageId = MessageId::new(0x01);
+}
+
+impl MessageEncode for DeviceId {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        BigEndian::write_u32(frame.payload_mut()?, self.0);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for DeviceId {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(DeviceId(BigEndian::read_u32(payload)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn product_family() {
+        let did = DeviceId(0x0370_0F30);
+        assert_eq!(did.product_family(), ProductFamily::Mti100Series);
+        assert_eq!(did.product_type(), 3);
+        let did = DeviceId(0x0160_1234);
+        assert_eq!(did.product_family(), ProductFamily::Mti10Series);
+        assert_eq!(did.product_type(), 1);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = [0_u8; 9];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        DeviceId(0x0770_02A1).encode(&mut f).unwrap();
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(f.message_id(), DeviceId::MSG_ID);
+        assert_eq!(DeviceId::decode(&f), Ok(DeviceId(0x0770_02A1)));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use core::{cmp, fmt};
+
+enum_with_unknown! {
+    /// Error codes reported by the device in an Error message
+    pub enum ErrorCode(u8) {
+        /// Period sent is not within valid range
+        PeriodInvalid = 0x03,
+        /// Message sent is invalid
+        MessageInvalid = 0x04,
+        /// Timer overflow, this can be caused by a too high output frequency
+        /// or sending too much data to the device during measurement
+        TimerOverflow = 0x1E,
+        /// Requested baud rate is not within the valid range
+        BaudRate = 0x20,
+        /// Invalid parameter in the message
+        InvalidParameter = 0x21,
+        /// Device error, the payload may contain an extended error code
+        DeviceError = 0x28,
+        /// The device generates more data than the bus communication can handle
+        DataOverflow = 0x29,
+        /// The sample buffer of the device was full during a communication outage
+        BufferOverflow = 0x2A,
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorCode::Unknown(c) => write!(f, "Unknown(0x{:02X})", c),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// Sent by the device when an error occurred or a command was rejected
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Error<'a> {
+    pub code: ErrorCode,
+    /// Optional additional data following the error code
+    pub data: &'a [u8],
+}
+
+impl<'a> Error<'a> {
+    pub fn new(code: ErrorCode) -> Self {
+        Error { code, data: &[] }
+    }
+}
+
+impl<'a> fmt::Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error({}), Len({})", self.code, self.data.len())
+    }
+}
+
+mod field {
+    use crate::wire::Rest;
+
+    pub const CODE: usize = 0;
+    pub const DATA: Rest = 1..;
+}
+
+impl<'a> MessageExt for Error<'a> {
+    const MSG_ID: MessageId = MessageId::new(0x42);
+}
+
+impl<'a> MessageEncode for Error<'a> {
+    const MAX_PAYLOAD_LEN: usize = PayloadLength::MAX_EXT as usize;
+
+    fn payload_len(&self) -> usize {
+        cmp::min(Self::MAX_PAYLOAD_LEN, field::DATA.start + self.data.len())
+    }
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        if field::DATA.start + self.data.len() > Self::MAX_PAYLOAD_LEN {
+            return Err(FrameError::InvalidPayloadLength);
+        }
+        frame.set_payload_length(
+            PayloadLength::new(self.payload_len()).ok_or(FrameError::InvalidPayloadLength)?,
+        );
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        payload[field::CODE] = self.code.into();
+        payload[field::DATA].copy_from_slice(self.data);
+        Ok(())
+    }
+}
+
+impl<'a> MessageDecode<'a> for Error<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.is_empty() {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(Error {
+                code: payload[field::CODE].into(),
+                data: &payload[field::DATA],
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    static MSG: [u8; 6] = [0xFA, 0xFF, 0x42, 0x01, 0x04, 0xBA];
+
+    #[test]
+    fn decode() {
+        let f = Frame::new(&MSG[..]).unwrap();
+        let e = Error::decode(&f).unwrap();
+        assert_eq!(e.code, ErrorCode::MessageInvalid);
+        assert!(e.data.is_empty());
+    }
+}
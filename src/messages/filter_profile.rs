@@ -0,0 +1,324 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    wire::ascii_field,
+};
+use core::fmt;
+
+/// A filter profile supported by the device
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FilterProfile<'a> {
+    pub profile_type: u8,
+    pub version: u8,
+    /// The profile name, i.e. "General" or "VRU"
+    pub label: &'a str,
+}
+
+impl<'a> fmt::Display for FilterProfile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FilterProfile({}, Type({}), Version({}))",
+            self.label, self.profile_type, self.version
+        )
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const TYPE: usize = 0;
+    pub const VERSION: usize = 1;
+    pub const LABEL: Field = 2..22;
+}
+
+impl<'a> FilterProfile<'a> {
+    pub const WIRE_SIZE: usize = field::LABEL.end;
+    pub const LABEL_SIZE: usize = field::LABEL.end - field::LABEL.start;
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FilterProfileIterator<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> FilterProfileIterator<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        FilterProfileIterator { buffer }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = FilterProfile<'a>> + 'a {
+        self.buffer
+            .chunks_exact(FilterProfile::WIRE_SIZE)
+            .map(|chunk| FilterProfile {
+                profile_type: chunk[field::TYPE],
+                version: chunk[field::VERSION],
+                label: ascii_field(&chunk[field::LABEL]),
+            })
+    }
+}
+
+/// Request the filter profiles supported by the device
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqAvailableFilterProfiles;
+
+impl MessageExt for ReqAvailableFilterProfiles {
+    const MSG_ID: MessageId = MessageId::new(0x62);
+}
+
+impl MessageEncode for ReqAvailableFilterProfiles {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqAvailableFilterProfiles {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqAvailableFilterProfiles)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct AvailableFilterProfiles<'a>(pub FilterProfileIterator<'a>);
+
+impl<'a> MessageExt for AvailableFilterProfiles<'a> {
+    const MSG_ID: MessageId = MessageId::new(0x63);
+}
+
+impl<'a> MessageDecode<'a> for AvailableFilterProfiles<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        Ok(AvailableFilterProfiles(FilterProfileIterator::new(payload)))
+    }
+}
+
+/// Request the active filter profile
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqFilterProfile;
+
+impl MessageExt for ReqFilterProfile {
+    const MSG_ID: MessageId = MessageId::new(0x64);
+}
+
+impl MessageEncode for ReqFilterProfile {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqFilterProfile {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqFilterProfile)
+    }
+}
+
+mod selection_field {
+    pub const VERSION: usize = 0;
+    pub const TYPE: usize = 1;
+}
+
+/// The active filter profile
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqFilterProfileAck {
+    pub profile_type: u8,
+    pub version: u8,
+}
+
+impl ReqFilterProfileAck {
+    pub const WIRE_SIZE: usize = 2;
+}
+
+impl MessageExt for ReqFilterProfileAck {
+    const MSG_ID: MessageId = MessageId::new(0x65);
+}
+
+impl MessageEncode for ReqFilterProfileAck {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        payload[selection_field::VERSION] = self.version;
+        payload[selection_field::TYPE] = self.profile_type;
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqFilterProfileAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(ReqFilterProfileAck {
+                profile_type: payload[selection_field::TYPE],
+                version: payload[selection_field::VERSION],
+            })
+        }
+    }
+}
+
+/// Select the filter profile used by the device, only valid in Config State.
+///
+/// `profile_type` must be one of the types reported by [`AvailableFilterProfiles`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetFilterProfile {
+    pub profile_type: u8,
+}
+
+impl SetFilterProfile {
+    pub const WIRE_SIZE: usize = 2;
+}
+
+impl MessageExt for SetFilterProfile {
+    const MSG_ID: MessageId = MessageId::new(0x64);
+}
+
+impl MessageEncode for SetFilterProfile {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        // The version is selected by the device
+        payload[selection_field::VERSION] = 0;
+        payload[selection_field::TYPE] = self.profile_type;
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetFilterProfile {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(SetFilterProfile {
+                profile_type: payload[selection_field::TYPE],
+            })
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetFilterProfileAck;
+
+impl MessageExt for SetFilterProfileAck {
+    const MSG_ID: MessageId = MessageId::new(0x65);
+}
+
+impl MessageEncode for SetFilterProfileAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetFilterProfileAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetFilterProfileAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::encode_frame;
+    use crate::message::BusId;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 49] = [
+        0xFA, 0xFF, 0x63, 0x2C,
+        0x27, 0x01,
+        b'G', b'e', b'n', b'e', b'r', b'a', b'l', b' ', b' ', b' ',
+        b' ', b' ', b' ', b' ', b' ', b' ', b' ', b' ', b' ', b' ',
+        0x2B, 0x02,
+        b'V', b'R', b'U', b'_', b'g', b'e', b'n', b'e', b'r', b'a',
+        b'l', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00,
+    ];
+
+    // Version 2 of the VRU_general profile (type 43)
+    static REQ_MSG: [u8; 5] = [0xFA, 0xFF, 0x64, 0x00, 0x9D];
+    static REQ_ACK_MSG: [u8; 7] = [0xFA, 0xFF, 0x65, 0x02, 0x02, 0x2B, 0x6D];
+    static SET_MSG: [u8; 7] = [0xFA, 0xFF, 0x64, 0x02, 0x00, 0x2B, 0x70];
+    static SET_ACK_MSG: [u8; 5] = [0xFA, 0xFF, 0x65, 0x00, 0x9C];
+
+    #[test]
+    fn available_profiles() {
+        let f = Frame::new_unchecked(&MSG[..]);
+        let msg = AvailableFilterProfiles::decode(&f).unwrap();
+        let mut iter = msg.0.iter();
+        assert_eq!(
+            iter.next(),
+            Some(FilterProfile {
+                profile_type: 39,
+                version: 1,
+                label: "General",
+            })
+        );
+        assert_eq!(
+            iter.next(),
+            Some(FilterProfile {
+                profile_type: 43,
+                version: 2,
+                label: "VRU_general",
+            })
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn req_filter_profile() {
+        let mut bytes = [0_u8; 5];
+        assert_eq!(
+            encode_frame(&ReqFilterProfile, BusId::MASTER, &mut bytes),
+            Ok(5)
+        );
+        assert_eq!(bytes, REQ_MSG);
+
+        let ack = ReqFilterProfileAck {
+            profile_type: 43,
+            version: 2,
+        };
+        let f = Frame::new(&REQ_ACK_MSG[..]).unwrap();
+        assert_eq!(ReqFilterProfileAck::decode(&f), Ok(ack));
+
+        let mut bytes = [0_u8; 7];
+        assert_eq!(encode_frame(&ack, BusId::MASTER, &mut bytes), Ok(7));
+        assert_eq!(bytes, REQ_ACK_MSG);
+
+        let short = [0xFA, 0xFF, 0x65, 0x01, 0x02, 0x99];
+        let f = Frame::new(&short[..]).unwrap();
+        assert_eq!(
+            ReqFilterProfileAck::decode(&f),
+            Err(FrameError::InvalidMessagePayload)
+        );
+    }
+
+    #[test]
+    fn set_filter_profile() {
+        let msg = SetFilterProfile { profile_type: 43 };
+        let mut bytes = [0_u8; 7];
+        assert_eq!(encode_frame(&msg, BusId::MASTER, &mut bytes), Ok(7));
+        assert_eq!(bytes, SET_MSG);
+
+        let f = Frame::new(&SET_MSG[..]).unwrap();
+        assert_eq!(SetFilterProfile::decode(&f), Ok(msg));
+
+        let f = Frame::new(&SET_ACK_MSG[..]).unwrap();
+        assert_eq!(SetFilterProfileAck::decode(&f), Ok(SetFilterProfileAck));
+    }
+}
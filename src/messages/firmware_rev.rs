@@ -0,0 +1,163 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+/// Request the firmware revision
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqFwRev;
+
+impl MessageExt for ReqFwRev {
+    const MSG_ID: MessageId = MessageId::new(0x12);
+}
+
+impl MessageEncode for ReqFwRev {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqFwRev {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqFwRev)
+    }
+}
+
+/// Firmware revision.
+/// Older firmware only reports the major, minor and revision fields,
+/// in which case build and scm are zero.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FirmwareRevision {
+    pub major: u8,
+    pub minor: u8,
+    pub revision: u8,
+    pub build: u32,
+    pub scm: u32,
+}
+
+impl fmt::Display for FirmwareRevision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}, Build({}), Scm({})",
+            self.major, self.minor, self.revision, self.build, self.scm
+        )
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const MAJOR: usize = 0;
+    pub const MINOR: usize = 1;
+    pub const REVISION: usize = 2;
+    pub const BUILD: Field = 3..7;
+    pub const SCM: Field = 7..11;
+}
+
+impl FirmwareRevision {
+    pub const MIN_WIRE_SIZE: usize = 3;
+    pub const WIRE_SIZE: usize = 11;
+}
+
+/// Firmware revision of the device
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FirmwareRev(pub FirmwareRevision);
+
+impl MessageExt for FirmwareRev {
+    const MSG_ID: MessageId = MessageId::new(0x13);
+}
+
+impl MessageEncode for FirmwareRev {
+    const MAX_PAYLOAD_LEN: usize = FirmwareRevision::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(FirmwareRevision::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        payload[field::MAJOR] = self.0.major;
+        payload[field::MINOR] = self.0.minor;
+        payload[field::REVISION] = self.0.revision;
+        BigEndian::write_u32(&mut payload[field::BUILD], self.0.build);
+        BigEndian::write_u32(&mut payload[field::SCM], self.0.scm);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for FirmwareRev {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < FirmwareRevision::MIN_WIRE_SIZE {
+            return Err(FrameError::InvalidMessagePayload);
+        }
+        let (build, scm) = if payload.len() >= FirmwareRevision::WIRE_SIZE {
+            (
+                BigEndian::read_u32(&payload[field::BUILD]),
+                BigEndian::read_u32(&payload[field::SCM]),
+            )
+        } else {
+            (0, 0)
+        };
+        Ok(FirmwareRev(FirmwareRevision {
+            major: payload[field::MAJOR],
+            minor: payload[field::MINOR],
+            revision: payload[field::REVISION],
+            build,
+            scm,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 16] = [
+        0xFA, 0xFF, 0x13, 0x0B,
+        0x01, 0x08, 0x02,
+        0x00, 0x00, 0x00, 0x2F,
+        0x00, 0x01, 0xE2, 0x40,
+        0x00,
+    ];
+
+    static LEGACY_MSG: [u8; 8] = [0xFA, 0xFF, 0x13, 0x03, 0x01, 0x02, 0x03, 0x00];
+
+    #[test]
+    fn decode() {
+        let f = Frame::new_unchecked(&MSG[..]);
+        let rev = FirmwareRev::decode(&f).unwrap().0;
+        assert_eq!(
+            rev,
+            FirmwareRevision {
+                major: 1,
+                minor: 8,
+                revision: 2,
+                build: 47,
+                scm: 123456,
+            }
+        );
+    }
+
+    #[test]
+    fn decode_legacy() {
+        let f = Frame::new_unchecked(&LEGACY_MSG[..]);
+        let rev = FirmwareRev::decode(&f).unwrap().0;
+        assert_eq!((rev.major, rev.minor, rev.revision), (1, 2, 3));
+        assert_eq!((rev.build, rev.scm), (0, 0));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = [0_u8; 16];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        let rev = FirmwareRev::decode(&Frame::new_unchecked(&MSG[..])).unwrap();
+        rev.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..15], &MSG[2..15]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+mod field {
+    use crate::wire::{field32, Field};
+
+    pub const X: Field = field32::F0;
+    pub const Y: Field = field32::F1;
+    pub const Z: Field = field32::F2;
+}
+
+/// Set the position of the GNSS antenna relative to the device,
+/// expressed in the sensor frame in meters
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct SetGnssLeverArm {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl SetGnssLeverArm {
+    pub const WIRE_SIZE: usize = 12;
+}
+
+impl fmt::Display for SetGnssLeverArm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X({:.3}), Y({:.3}), Z({:.3})", self.x, self.y, self.z)
+    }
+}
+
+impl MessageExt for SetGnssLeverArm {
+    const MSG_ID: MessageId = MessageId::new(0x68);
+}
+
+impl MessageEncode for SetGnssLeverArm {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        BigEndian::write_f32(&mut payload[field::X], self.x);
+        BigEndian::write_f32(&mut payload[field::Y], self.y);
+        BigEndian::write_f32(&mut payload[field::Z], self.z);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetGnssLeverArm {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(SetGnssLeverArm {
+                x: BigEndian::read_f32(&payload[field::X]),
+                y: BigEndian::read_f32(&payload[field::Y]),
+                z: BigEndian::read_f32(&payload[field::Z]),
+            })
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetGnssLeverArmAck;
+
+impl MessageExt for SetGnssLeverArmAck {
+    const MSG_ID: MessageId = MessageId::new(0x69);
+}
+
+impl MessageEncode for SetGnssLeverArmAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetGnssLeverArmAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetGnssLeverArmAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 17] = [
+        0xFA, 0xFF, 0x68, 0x0C,
+        0x3F, 0x80, 0x00, 0x00,
+        0xBF, 0x00, 0x00, 0x00,
+        0x3E, 0x80, 0x00, 0x00,
+        0x00,
+    ];
+
+    #[test]
+    fn round_trip() {
+        let f = Frame::new_unchecked(&MSG[..]);
+        let msg = SetGnssLeverArm::decode(&f).unwrap();
+        assert_relative_eq!(msg.x, 1.0);
+        assert_relative_eq!(msg.y, -0.5);
+        assert_relative_eq!(msg.z, 0.25);
+
+        let mut bytes = [0_u8; 17];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        msg.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..16], &MSG[2..16]);
+    }
+
+    #[test]
+    fn missing_payload() {
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x68, 0x04, 0x3F, 0x80, 0x00, 0x00, 0x00][..]);
+        assert_eq!(
+            SetGnssLeverArm::decode(&f).unwrap_err(),
+            FrameError::InvalidMessagePayload
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+
+enum_with_unknown! {
+    /// The dynamic model used by the GNSS receiver
+    pub enum GnssPlatform(u16) {
+        Portable = 0,
+        Stationary = 2,
+        Pedestrian = 3,
+        Automotive = 4,
+        AtSea = 5,
+        /// Airborne with less than 1g acceleration
+        Airborne1g = 6,
+        /// Airborne with less than 2g acceleration
+        Airborne2g = 7,
+        /// Airborne with less than 4g acceleration
+        Airborne4g = 8,
+        Wrist = 9,
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const PLATFORM: Field = 0..2;
+}
+
+impl GnssPlatform {
+    pub const WIRE_SIZE: usize = 2;
+
+    fn decode_payload(payload: &[u8]) -> Result<Self, FrameError> {
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(BigEndian::read_u16(&payload[field::PLATFORM]).into())
+        }
+    }
+
+    fn encode_payload(self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        BigEndian::write_u16(&mut frame.payload_mut()?[field::PLATFORM], self.into());
+        Ok(())
+    }
+}
+
+/// Request the GNSS platform setting
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqGnssPlatform;
+
+impl MessageExt for ReqGnssPlatform {
+    const MSG_ID: MessageId = MessageId::new(0x76);
+}
+
+impl MessageEncode for ReqGnssPlatform {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqGnssPlatform {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqGnssPlatform)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqGnssPlatformAck(pub GnssPlatform);
+
+impl MessageExt for ReqGnssPlatformAck {
+    const MSG_ID: MessageId = MessageId::new(0x77);
+}
+
+impl MessageEncode for ReqGnssPlatformAck {
+    const MAX_PAYLOAD_LEN: usize = GnssPlatform::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        self.0.encode_payload(frame)
+    }
+}
+
+impl MessageDecode<'_> for ReqGnssPlatformAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        GnssPlatform::decode_payload(frame.payload()?).map(ReqGnssPlatformAck)
+    }
+}
+
+/// Set the GNSS platform, only valid in Config State
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetGnssPlatform(pub GnssPlatform);
+
+impl MessageExt for SetGnssPlatform {
+    const MSG_ID: MessageId = MessageId::new(0x76);
+}
+
+impl MessageEncode for SetGnssPlatform {
+    const MAX_PAYLOAD_LEN: usize = GnssPlatform::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        self.0.encode_payload(frame)
+    }
+}
+
+impl MessageDecode<'_> for SetGnssPlatform {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        GnssPlatform::decode_payload(frame.payload()?).map(SetGnssPlatform)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetGnssPlatformAck;
+
+impl MessageExt for SetGnssPlatformAck {
+    const MSG_ID: MessageId = MessageId::new(0x77);
+}
+
+impl MessageEncode for SetGnssPlatformAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetGnssPlatformAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetGnssPlatformAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = [0_u8; 7];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        SetGnssPlatform(GnssPlatform::Airborne2g)
+            .encode(&mut f)
+            .unwrap();
+        assert_eq!(&bytes[2..6], &[0x76, 0x02, 0x00, 0x07]);
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(
+            SetGnssPlatform::decode(&f),
+            Ok(SetGnssPlatform(GnssPlatform::Airborne2g))
+        );
+    }
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x77, 0x02, 0x00, 0x04, 0x00][..]);
+        assert_eq!(
+            ReqGnssPlatformAck::decode(&f),
+            Ok(ReqGnssPlatformAck(GnssPlatform::Automotive))
+        );
+
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x77, 0x02, 0x00, 0x01, 0x00][..]);
+        assert_eq!(
+            ReqGnssPlatformAck::decode(&f),
+            Ok(ReqGnssPlatformAck(GnssPlatform::Unknown(1)))
+        );
+
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x77, 0x00, 0x00][..]);
+        assert_eq!(
+            ReqGnssPlatformAck::decode(&f),
+            Err(FrameError::InvalidMessagePayload)
+        );
+    }
+}
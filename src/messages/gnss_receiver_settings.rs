@@ -0,0 +1,158 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    messages::BaudRate,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+/// Settings of the communication between the device and its GNSS receiver
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct GnssReceiverSettings {
+    pub receiver_type: u16,
+    pub baud_rate: BaudRate,
+    /// Input rate of the GNSS data in Hz
+    pub input_rate: u16,
+    pub options: u32,
+}
+
+impl fmt::Display for GnssReceiverSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Type({}), BaudRate({}), InputRate({}), Options(0x{:08X})",
+            self.receiver_type, self.baud_rate, self.input_rate, self.options
+        )
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const TYPE: Field = 0..2;
+    pub const BAUD_RATE: Field = 2..4;
+    pub const INPUT_RATE: Field = 4..6;
+    pub const OPTIONS: Field = 6..10;
+}
+
+impl GnssReceiverSettings {
+    pub const WIRE_SIZE: usize = 10;
+}
+
+/// Request the GNSS receiver settings
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqGnssReceiverSettings;
+
+impl MessageExt for ReqGnssReceiverSettings {
+    const MSG_ID: MessageId = MessageId::new(0xAC);
+}
+
+impl MessageEncode for ReqGnssReceiverSettings {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqGnssReceiverSettings {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqGnssReceiverSettings)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqGnssReceiverSettingsAck(pub GnssReceiverSettings);
+
+impl MessageExt for ReqGnssReceiverSettingsAck {
+    const MSG_ID: MessageId = MessageId::new(0xAD);
+}
+
+impl MessageEncode for ReqGnssReceiverSettingsAck {
+    const MAX_PAYLOAD_LEN: usize = GnssReceiverSettings::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(
+            GnssReceiverSettings::WIRE_SIZE as u8,
+        ));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        BigEndian::write_u16(&mut payload[field::TYPE], self.0.receiver_type);
+        BigEndian::write_u16(
+            &mut payload[field::BAUD_RATE],
+            u8::from(self.0.baud_rate).into(),
+        );
+        BigEndian::write_u16(&mut payload[field::INPUT_RATE], self.0.input_rate);
+        BigEndian::write_u32(&mut payload[field::OPTIONS], self.0.options);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqGnssReceiverSettingsAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < GnssReceiverSettings::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            let baud_rate = BigEndian::read_u16(&payload[field::BAUD_RATE]);
+            Ok(ReqGnssReceiverSettingsAck(GnssReceiverSettings {
+                receiver_type: BigEndian::read_u16(&payload[field::TYPE]),
+                // Codes that don't fit the 8-bit baud rate code are never valid
+                baud_rate: u8::try_from(baud_rate)
+                    .map(BaudRate::from)
+                    .unwrap_or(BaudRate::Unknown(u8::MAX)),
+                input_rate: BigEndian::read_u16(&payload[field::INPUT_RATE]),
+                options: BigEndian::read_u32(&payload[field::OPTIONS]),
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 15] = [
+        0xFA, 0xFF, 0xAD, 0x0A,
+        0x00, 0x03,
+        0x00, 0x02,
+        0x00, 0x04,
+        0x00, 0x00, 0x00, 0x01,
+        0x00,
+    ];
+
+    #[test]
+    fn round_trip() {
+        let f = Frame::new_unchecked(&MSG[..]);
+        let msg = ReqGnssReceiverSettingsAck::decode(&f).unwrap();
+        assert_eq!(
+            msg.0,
+            GnssReceiverSettings {
+                receiver_type: 3,
+                baud_rate: BaudRate::Baud115200,
+                input_rate: 4,
+                options: 1,
+            }
+        );
+
+        let mut bytes = [0_u8; 15];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        msg.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..14], &MSG[2..14]);
+    }
+
+    #[test]
+    fn out_of_range_baud_rate() {
+        let mut bytes = MSG;
+        bytes[6] = 0x01;
+        bytes[7] = 0x02;
+        let f = Frame::new_unchecked(&bytes[..]);
+        let msg = ReqGnssReceiverSettingsAck::decode(&f).unwrap();
+        assert_eq!(msg.0.baud_rate, BaudRate::Unknown(u8::MAX));
+        assert_eq!(msg.0.baud_rate.bits_per_second(), None);
+    }
+}
@@ -13,6 +13,8 @@ impl MessageExt for GoToConfig {
 }
 
 impl MessageEncode for GoToConfig {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
     fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
         frame.set_payload_length(PayloadLength::Standard(0));
         Ok(())
@@ -33,6 +35,8 @@ impl MessageExt for GoToConfigAck {
 }
 
 impl MessageEncode for GoToConfigAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
     fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
         frame.set_payload_length(PayloadLength::Standard(0));
         Ok(())
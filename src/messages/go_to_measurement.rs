@@ -10,6 +10,8 @@ impl MessageExt for GoToMeasurement {
 }
 
 impl MessageEncode for GoToMeasurement {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
     fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
         frame.set_payload_length(PayloadLength::Standard(0));
         Ok(())
@@ -30,6 +32,8 @@ impl MessageExt for GoToMeasurementAck {
 }
 
 impl MessageEncode for GoToMeasurementAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
     fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
         frame.set_payload_length(PayloadLength::Standard(0));
         Ok(())
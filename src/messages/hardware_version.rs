@@ -0,0 +1,107 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use core::fmt;
+
+/// Request the hardware version
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqHardwareVersion;
+
+impl MessageExt for ReqHardwareVersion {
+    const MSG_ID: MessageId = MessageId::new(0x1E);
+}
+
+impl MessageEncode for ReqHardwareVersion {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqHardwareVersion {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqHardwareVersion)
+    }
+}
+
+/// Hardware version of the device
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct HardwareVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl fmt::Display for HardwareVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+mod field {
+    pub const MAJOR: usize = 0;
+    pub const MINOR: usize = 1;
+}
+
+impl HardwareVersion {
+    pub const WIRE_SIZE: usize = 2;
+}
+
+impl MessageExt for HardwareVersion {
+    const MSG_ID: MessageId = MessageId::new(0x1F);
+}
+
+impl MessageEncode for HardwareVersion {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        payload[field::MAJOR] = self.major;
+        payload[field::MINOR] = self.minor;
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for HardwareVersion {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(HardwareVersion {
+                major: payload[field::MAJOR],
+                minor: payload[field::MINOR],
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    static MSG: [u8; 7] = [0xFA, 0xFF, 0x1F, 0x02, 0x01, 0x02, 0xDD];
+
+    #[test]
+    fn decode() {
+        let f = Frame::new(&MSG[..]).unwrap();
+        assert_eq!(
+            HardwareVersion::decode(&f),
+            Ok(HardwareVersion { major: 1, minor: 2 })
+        );
+    }
+
+    #[test]
+    fn decode_short_payload() {
+        static SHORT_MSG: [u8; 6] = [0xFA, 0xFF, 0x1F, 0x01, 0x01, 0xE0];
+        let f = Frame::new(&SHORT_MSG[..]).unwrap();
+        assert_eq!(
+            HardwareVersion::decode(&f),
+            Err(FrameError::InvalidMessagePayload)
+        );
+    }
+}
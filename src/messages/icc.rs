@@ -0,0 +1,227 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+enum_with_unknown! {
+    /// In-run Compass Calibration sub-commands
+    pub enum IccCommandCode(u8) {
+        /// Start the representative motion
+        Start = 0x00,
+        /// Stop the representative motion and compute the calibration result
+        Stop = 0x01,
+        /// Store the calibration result on the device
+        Store = 0x02,
+        /// Request whether the representative motion is in progress
+        GetState = 0x03,
+    }
+}
+
+/// The magnetic field calibration result computed at the end of the representative motion
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct IccResult {
+    /// Magnetic field disturbance estimate after calibration, a lower value is better
+    pub ddt: f32,
+    /// Number of dimensions (2 or 3) the motion was rich enough to calibrate
+    pub dimension: u8,
+    /// Calibration status, non-zero when the result was rejected
+    pub status: u8,
+}
+
+impl IccResult {
+    pub const WIRE_SIZE: usize = 6;
+}
+
+impl fmt::Display for IccResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DDT({:.3}), Dimension({}), Status({})",
+            self.ddt, self.dimension, self.status
+        )
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const COMMAND: usize = 0;
+    pub const DDT: Field = 1..5;
+    pub const DIMENSION: usize = 5;
+    pub const STATUS: usize = 6;
+    pub const STATE: usize = 1;
+}
+
+/// Send an In-run Compass Calibration (ICC) sub-command, only valid in Measurement State.
+/// Requires the ICC option flag to be enabled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct IccCommand {
+    pub command: IccCommandCode,
+}
+
+impl IccCommand {
+    pub const WIRE_SIZE: usize = 1;
+
+    pub fn new(command: IccCommandCode) -> Self {
+        IccCommand { command }
+    }
+}
+
+impl MessageExt for IccCommand {
+    const MSG_ID: MessageId = MessageId::new(0x74);
+}
+
+impl MessageEncode for IccCommand {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        frame.payload_mut()?[field::COMMAND] = self.command.into();
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for IccCommand {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.is_empty() {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(IccCommand {
+                command: payload[field::COMMAND].into(),
+            })
+        }
+    }
+}
+
+/// The response to an [`IccCommand`], contents depend on the sub-command
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum IccCommandAck {
+    Start,
+    Stop(IccResult),
+    Store,
+    /// True if the representative motion is in progress
+    GetState(bool),
+    Unknown(u8),
+}
+
+impl IccCommandAck {
+    pub fn command(&self) -> IccCommandCode {
+        match self {
+            IccCommandAck::Start => IccCommandCode::Start,
+            IccCommandAck::Stop(_) => IccCommandCode::Stop,
+            IccCommandAck::Store => IccCommandCode::Store,
+            IccCommandAck::GetState(_) => IccCommandCode::GetState,
+            IccCommandAck::Unknown(c) => IccCommandCode::Unknown(*c),
+        }
+    }
+}
+
+impl MessageExt for IccCommandAck {
+    const MSG_ID: MessageId = MessageId::new(0x75);
+}
+
+impl MessageEncode for IccCommandAck {
+    const MAX_PAYLOAD_LEN: usize = 1 + IccResult::WIRE_SIZE;
+
+    fn payload_len(&self) -> usize {
+        match self {
+            IccCommandAck::Stop(_) => 1 + IccResult::WIRE_SIZE,
+            IccCommandAck::GetState(_) => 2,
+            _ => 1,
+        }
+    }
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(self.payload_len() as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        payload[field::COMMAND] = self.command().into();
+        match self {
+            IccCommandAck::Stop(r) => {
+                BigEndian::write_f32(&mut payload[field::DDT], r.ddt);
+                payload[field::DIMENSION] = r.dimension;
+                payload[field::STATUS] = r.status;
+            }
+            IccCommandAck::GetState(running) => payload[field::STATE] = *running as u8,
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for IccCommandAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.is_empty() {
+            return Err(FrameError::InvalidMessagePayload);
+        }
+        Ok(match IccCommandCode::from(payload[field::COMMAND]) {
+            IccCommandCode::Start => IccCommandAck::Start,
+            IccCommandCode::Stop => {
+                if payload.len() < 1 + IccResult::WIRE_SIZE {
+                    return Err(FrameError::InvalidMessagePayload);
+                }
+                IccCommandAck::Stop(IccResult {
+                    ddt: BigEndian::read_f32(&payload[field::DDT]),
+                    dimension: payload[field::DIMENSION],
+                    status: payload[field::STATUS],
+                })
+            }
+            IccCommandCode::Store => IccCommandAck::Store,
+            IccCommandCode::GetState => {
+                if payload.len() < 2 {
+                    return Err(FrameError::InvalidMessagePayload);
+                }
+                IccCommandAck::GetState(payload[field::STATE] != 0)
+            }
+            IccCommandCode::Unknown(c) => IccCommandAck::Unknown(c),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static STOP_ACK_MSG: [u8; 12] = [
+        0xFA, 0xFF, 0x75, 0x07,
+        0x01,
+        0x3E, 0x80, 0x00, 0x00,
+        0x03,
+        0x00,
+        0x00,
+    ];
+
+    #[test]
+    fn stop_ack_round_trip() {
+        let f = Frame::new_unchecked(&STOP_ACK_MSG[..]);
+        let msg = IccCommandAck::decode(&f).unwrap();
+        assert_eq!(msg.command(), IccCommandCode::Stop);
+        match msg {
+            IccCommandAck::Stop(r) => {
+                assert_relative_eq!(r.ddt, 0.25);
+                assert_eq!(r.dimension, 3);
+                assert_eq!(r.status, 0);
+            }
+            _ => panic!("Expected a stop result"),
+        }
+
+        let mut bytes = [0_u8; 12];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        msg.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..11], &STOP_ACK_MSG[2..11]);
+    }
+
+    #[test]
+    fn get_state_ack() {
+        let bytes = [0xFA, 0xFF, 0x75, 0x02, 0x03, 0x01, 0x00];
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(IccCommandAck::decode(&f), Ok(IccCommandAck::GetState(true)));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    mtdata2::{AltitudeEllipsoid, LatLon},
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+mod field {
+    use crate::wire::{field64, Field};
+
+    pub const LAT_LON: Field = field64::F0.start..field64::F1.end;
+    pub const LAT: Field = field64::F0;
+    pub const LON: Field = field64::F1;
+    pub const ALT: Field = field64::F2;
+}
+
+/// Set the initial position of a GNSS/INS device, used by the filter
+/// until a GNSS fix is available
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct SetLatLonAlt {
+    pub lat_lon: LatLon<f64>,
+    pub altitude: AltitudeEllipsoid<f64>,
+}
+
+impl SetLatLonAlt {
+    pub const WIRE_SIZE: usize = LatLon::<f64>::WIRE_SIZE + AltitudeEllipsoid::<f64>::WIRE_SIZE;
+
+    pub fn new(lat_lon: LatLon<f64>, altitude: AltitudeEllipsoid<f64>) -> Self {
+        SetLatLonAlt { lat_lon, altitude }
+    }
+}
+
+impl fmt::Display for SetLatLonAlt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, Alt({})", self.lat_lon, self.altitude)
+    }
+}
+
+impl MessageExt for SetLatLonAlt {
+    const MSG_ID: MessageId = MessageId::new(0x6E);
+}
+
+impl MessageEncode for SetLatLonAlt {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        BigEndian::write_f64(&mut payload[field::LAT], self.lat_lon.lat);
+        BigEndian::write_f64(&mut payload[field::LON], self.lat_lon.lon);
+        BigEndian::write_f64(&mut payload[field::ALT], self.altitude.0);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetLatLonAlt {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            let lat_lon = LatLon::<f64>::from_be_slice(&payload[field::LAT_LON])
+                .map_err(|_| FrameError::InvalidMessagePayload)?;
+            let altitude = AltitudeEllipsoid::<f64>::from_be_slice(&payload[field::ALT])
+                .map_err(|_| FrameError::InvalidMessagePayload)?;
+            Ok(SetLatLonAlt { lat_lon, altitude })
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetLatLonAltAck;
+
+impl MessageExt for SetLatLonAltAck {
+    const MSG_ID: MessageId = MessageId::new(0x6F);
+}
+
+impl MessageEncode for SetLatLonAltAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetLatLonAltAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetLatLonAltAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn round_trip() {
+        let msg = SetLatLonAlt::new(
+            LatLon {
+                lat: 47.673,
+                lon: -117.153,
+            },
+            AltitudeEllipsoid(603.645),
+        );
+        let mut bytes = [0_u8; 29];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        msg.encode(&mut f).unwrap();
+        let f = Frame::new_unchecked(&bytes[..]);
+        let out = SetLatLonAlt::decode(&f).unwrap();
+        assert_relative_eq!(out.lat_lon.lat, 47.673);
+        assert_relative_eq!(out.lat_lon.lon, -117.153);
+        assert_relative_eq!(out.altitude.0, 603.645);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+
+mod field {
+    use crate::wire::Field;
+
+    pub const LOCATION_ID: Field = 0..2;
+}
+
+const WIRE_SIZE: usize = field::LOCATION_ID.end;
+
+fn encode_location_id(location_id: u16, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+    frame.set_payload_length(PayloadLength::Standard(WIRE_SIZE as u8));
+    frame.check_payload_length()?;
+    BigEndian::write_u16(&mut frame.payload_mut()?[field::LOCATION_ID], location_id);
+    Ok(())
+}
+
+fn decode_location_id(frame: &Frame<&[u8]>) -> Result<u16, FrameError> {
+    let payload = frame.payload()?;
+    if payload.len() < WIRE_SIZE {
+        Err(FrameError::InvalidMessagePayload)
+    } else {
+        Ok(BigEndian::read_u16(&payload[field::LOCATION_ID]))
+    }
+}
+
+/// Request the user defined location ID of the device
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqLocationId;
+
+impl MessageExt for ReqLocationId {
+    const MSG_ID: MessageId = MessageId::new(0x84);
+}
+
+impl MessageEncode for ReqLocationId {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqLocationId {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqLocationId)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqLocationIdAck(pub u16);
+
+impl MessageExt for ReqLocationIdAck {
+    const MSG_ID: MessageId = MessageId::new(0x85);
+}
+
+impl MessageEncode for ReqLocationIdAck {
+    const MAX_PAYLOAD_LEN: usize = WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_location_id(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for ReqLocationIdAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_location_id(frame).map(ReqLocationIdAck)
+    }
+}
+
+/// Set the user defined location ID of the device, only valid in Config State
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetLocationId(pub u16);
+
+impl MessageExt for SetLocationId {
+    const MSG_ID: MessageId = MessageId::new(0x84);
+}
+
+impl MessageEncode for SetLocationId {
+    const MAX_PAYLOAD_LEN: usize = WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_location_id(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for SetLocationId {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_location_id(frame).map(SetLocationId)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetLocationIdAck;
+
+impl MessageExt for SetLocationIdAck {
+    const MSG_ID: MessageId = MessageId::new(0x85);
+}
+
+impl MessageEncode for SetLocationIdAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetLocationIdAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetLocationIdAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = [0_u8; 7];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        SetLocationId(0x1234).encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..6], &[0x84, 0x02, 0x12, 0x34]);
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(SetLocationId::decode(&f), Ok(SetLocationId(0x1234)));
+    }
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new(&[0xFA, 0xFF, 0x85, 0x02, 0x00, 0x2A, 0x50][..]).unwrap();
+        assert_eq!(ReqLocationIdAck::decode(&f), Ok(ReqLocationIdAck(42)));
+
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x85, 0x01, 0x00, 0x00][..]);
+        assert_eq!(
+            ReqLocationIdAck::decode(&f),
+            Err(FrameError::InvalidMessagePayload)
+        );
+    }
+}
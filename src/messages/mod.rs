@@ -1,9 +1,73 @@
+pub mod alignment_rotation;
+pub mod any_message;
+pub mod baudrate;
+pub mod can_config;
+pub mod can_output_config;
+pub mod device_id;
+pub mod error;
+pub mod filter_profile;
+pub mod firmware_rev;
+pub mod gnss_lever_arm;
+pub mod gnss_platform;
+pub mod gnss_receiver_settings;
 pub mod go_to_config;
 pub mod go_to_measurement;
+pub mod hardware_version;
+pub mod icc;
+pub mod lat_lon_alt;
+pub mod location_id;
 pub mod mtdata2;
+pub mod mtdata_legacy;
+pub mod no_rotation;
+pub mod option_flags;
+pub mod output_mode;
+pub mod port_config;
+pub mod product_code;
+pub mod req_data;
+pub mod reset;
+pub mod reset_orientation;
+pub mod restore_factory_defaults;
+pub mod self_test;
 pub mod set_output_config;
+pub mod set_utc_time;
+pub mod store_filter_state;
+pub mod sync_settings;
+pub mod wake_up;
+pub mod warning;
 
+pub use alignment_rotation::*;
+pub use any_message::*;
+pub use baudrate::*;
+pub use can_config::*;
+pub use can_output_config::*;
+pub use device_id::*;
+pub use error::*;
+pub use filter_profile::*;
+pub use firmware_rev::*;
+pub use gnss_lever_arm::*;
+pub use gnss_platform::*;
+pub use gnss_receiver_settings::*;
 pub use go_to_config::*;
 pub use go_to_measurement::*;
+pub use hardware_version::*;
+pub use icc::*;
+pub use lat_lon_alt::*;
+pub use location_id::*;
 pub use mtdata2::*;
+pub use mtdata_legacy::*;
+pub use no_rotation::*;
+pub use option_flags::*;
+pub use output_mode::*;
+pub use port_config::*;
+pub use product_code::*;
+pub use req_data::*;
+pub use reset::*;
+pub use reset_orientation::*;
+pub use restore_factory_defaults::*;
+pub use self_test::*;
 pub use set_output_config::*;
+pub use set_utc_time::*;
+pub use store_filter_state::*;
+pub use sync_settings::*;
+pub use wake_up::*;
+pub use warning::*;
@@ -16,6 +16,8 @@ use crate::{
     wire::{MTData2Packet, MTData2PacketIter, WireError},
 };
 
+/// Sent at the configured output rates in Measurement State, or once in response
+/// to a [`ReqData`](crate::messages::ReqData) when polling
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct MTData2<'a>(pub MTData2PacketIter<'a>);
 
@@ -0,0 +1,263 @@
+use crate::{
+    message::{Frame, FrameError, MessageDecode, MessageExt, MessageId},
+    messages::{OrientationMode, OutputMode, OutputSettings},
+    mtdata2::{Acceleration, EulerAngles, MagneticField, RateOfTurn, UtcTime},
+    precision::PrecisionExt,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::mem;
+
+/// Size of the raw inertial data, 10 unsigned 16-bit ADC values
+const RAW_INERTIAL_SIZE: usize = 20;
+/// Size of a single auxiliary analog input value
+const AUX_SIZE: usize = 2;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, err_derive::Error)]
+pub enum LegacyDataError {
+    #[error(display = "The output settings data format does not match the requested precision")]
+    PrecisionMismatch,
+
+    #[error(display = "Raw GPS data is not supported")]
+    UnsupportedOutputMode,
+
+    #[error(display = "Missing bytes")]
+    MissingBytes,
+}
+
+/// The orientation output of the legacy format, representation depends on
+/// [`OutputSettings::orientation_mode`]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum LegacyOrientation<T: PrecisionExt> {
+    /// Quaternion (q0, q1, q2, q3)
+    Quaternion([T; 4]),
+    EulerAngles(EulerAngles<T>),
+    /// Rotation matrix, column-major (a, b, c, d, e, f, g, h, i)
+    Matrix([T; 9]),
+}
+
+/// The data of a single legacy MTData sample, fields are present when
+/// enabled in the [`OutputMode`] and [`OutputSettings`]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct LegacyData<T: PrecisionExt> {
+    /// Internal temperature in degrees Celsius
+    pub temperature: Option<T>,
+    pub acceleration: Option<Acceleration<T>>,
+    pub rate_of_turn: Option<RateOfTurn<T>>,
+    pub magnetic_field: Option<MagneticField<T>>,
+    pub orientation: Option<LegacyOrientation<T>>,
+    pub status: Option<u8>,
+    pub sample_counter: Option<u16>,
+    pub utc_time: Option<UtcTime>,
+}
+
+/// Sensor data in the legacy MTData format, used by MTi-10/100 devices
+/// not configured for [`MTData2`](crate::messages::MTData2).
+///
+/// The layout of the payload depends on the device's [`OutputMode`] and [`OutputSettings`],
+/// use [`MTDataLegacy::data`] to decode it.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MTDataLegacy<'a>(pub &'a [u8]);
+
+impl<'a> MessageExt for MTDataLegacy<'a> {
+    const MSG_ID: MessageId = MessageId::new(0x32);
+}
+
+impl<'a> MessageDecode<'a> for MTDataLegacy<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        Ok(MTDataLegacy(payload))
+    }
+}
+
+impl<'a> MTDataLegacy<'a> {
+    /// Decode the payload using the device's output mode and settings.
+    ///
+    /// `T` must match the precision selected by [`OutputSettings::data_format`].
+    /// Auxiliary, position and velocity data are skipped.
+    pub fn data<T: PrecisionExt<NativeType = T>>(
+        &self,
+        mode: OutputMode,
+        settings: OutputSettings,
+    ) -> Result<LegacyData<T>, LegacyDataError> {
+        if settings.data_format() != T::PRECISION {
+            return Err(LegacyDataError::PrecisionMismatch);
+        }
+        if mode.raw_gps() {
+            return Err(LegacyDataError::UnsupportedOutputMode);
+        }
+
+        let mut r = Reader::new(self.0);
+        let mut data = LegacyData {
+            temperature: None,
+            acceleration: None,
+            rate_of_turn: None,
+            magnetic_field: None,
+            orientation: None,
+            status: None,
+            sample_counter: None,
+            utc_time: None,
+        };
+
+        if mode.raw_inertial() {
+            r.take(RAW_INERTIAL_SIZE)?;
+        }
+        if mode.temperature() {
+            data.temperature = Some(r.field()?);
+        }
+        if mode.calibrated() {
+            if !settings.disable_acceleration() {
+                let [x, y, z] = r.fields()?;
+                data.acceleration = Some(Acceleration { x, y, z });
+            }
+            if !settings.disable_rate_of_turn() {
+                let [x, y, z] = r.fields()?;
+                data.rate_of_turn = Some(RateOfTurn { x, y, z });
+            }
+            if !settings.disable_magnetic_field() {
+                let [x, y, z] = r.fields()?;
+                data.magnetic_field = Some(MagneticField { x, y, z });
+            }
+        }
+        if mode.orientation() {
+            data.orientation = Some(match settings.orientation_mode() {
+                OrientationMode::EulerAngles => {
+                    let [roll, pitch, yaw] = r.fields()?;
+                    LegacyOrientation::EulerAngles(EulerAngles { roll, pitch, yaw })
+                }
+                OrientationMode::Matrix => LegacyOrientation::Matrix(r.fields()?),
+                // Same as the device, anything else is a quaternion
+                _ => LegacyOrientation::Quaternion(r.fields()?),
+            });
+        }
+        if mode.auxiliary() {
+            if !settings.disable_aux1() {
+                r.take(AUX_SIZE)?;
+            }
+            if !settings.disable_aux2() {
+                r.take(AUX_SIZE)?;
+            }
+        }
+        if mode.position() {
+            r.fields::<T, 3>()?;
+        }
+        if mode.velocity() {
+            r.fields::<T, 3>()?;
+        }
+        if mode.status() {
+            data.status = Some(r.take(1)?[0]);
+        }
+        if settings.sample_counter() {
+            data.sample_counter = Some(BigEndian::read_u16(r.take(2)?));
+        }
+        if settings.utc_time() {
+            data.utc_time = Some(
+                UtcTime::from_be_slice(r.take(UtcTime::WIRE_SIZE)?)
+                    .map_err(|_| LegacyDataError::MissingBytes)?,
+            );
+        }
+
+        Ok(data)
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes }
+    }
+
+    fn take(&mut self, size: usize) -> Result<&'a [u8], LegacyDataError> {
+        if self.bytes.len() < size {
+            Err(LegacyDataError::MissingBytes)
+        } else {
+            let (field, rest) = self.bytes.split_at(size);
+            self.bytes = rest;
+            Ok(field)
+        }
+    }
+
+    fn field<T: PrecisionExt<NativeType = T>>(&mut self) -> Result<T, LegacyDataError> {
+        Ok(T::read_field(self.take(mem::size_of::<T>())?))
+    }
+
+    fn fields<T: PrecisionExt<NativeType = T>, const N: usize>(
+        &mut self,
+    ) -> Result<[T; N], LegacyDataError> {
+        let size = mem::size_of::<T>();
+        let bytes = self.take(N * size)?;
+        Ok(core::array::from_fn(|i| {
+            T::read_field(&bytes[i * size..(i + 1) * size])
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 60] = [
+        0xFA, 0xFF, 0x32, 0x37,
+        0x41, 0xCC, 0x00, 0x00,
+        0x3F, 0x00, 0x00, 0x00, 0xBF, 0x80, 0x00, 0x00, 0x41, 0x1C, 0x00, 0x00,
+        0x3E, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0x00, 0x00, 0x00,
+        0x3F, 0x80, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0xBF, 0x00, 0x00, 0x00,
+        0x41, 0x28, 0x00, 0x00, 0xC0, 0x10, 0x00, 0x00, 0x43, 0x34, 0x00, 0x00,
+        0x03,
+        0x12, 0x34,
+        0x7E,
+    ];
+
+    #[test]
+    fn decode() {
+        let f = Frame::new(&MSG[..]).unwrap();
+        let msg = MTDataLegacy::decode(&f).unwrap();
+        let mode = OutputMode(0x0807);
+        let settings = OutputSettings(0x0000_0005);
+        let data = msg.data::<f32>(mode, settings).unwrap();
+        assert_eq!(
+            data,
+            LegacyData {
+                temperature: Some(25.5),
+                acceleration: Some(Acceleration {
+                    x: 0.5,
+                    y: -1.0,
+                    z: 9.75
+                }),
+                rate_of_turn: Some(RateOfTurn {
+                    x: 0.25,
+                    y: 0.0,
+                    z: -0.125
+                }),
+                magnetic_field: Some(MagneticField {
+                    x: 1.0,
+                    y: 0.5,
+                    z: -0.5
+                }),
+                orientation: Some(LegacyOrientation::EulerAngles(EulerAngles {
+                    roll: 10.5,
+                    pitch: -2.25,
+                    yaw: 180.0
+                })),
+                status: Some(0x03),
+                sample_counter: Some(0x1234),
+                utc_time: None,
+            }
+        );
+
+        assert_eq!(
+            msg.data::<f64>(mode, settings),
+            Err(LegacyDataError::PrecisionMismatch)
+        );
+        let mut settings = settings;
+        settings.set_utc_time(true);
+        assert_eq!(
+            msg.data::<f32>(mode, settings),
+            Err(LegacyDataError::MissingBytes)
+        );
+    }
+}
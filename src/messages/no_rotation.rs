@@ -0,0 +1,100 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+
+mod field {
+    use crate::wire::Field;
+
+    pub const DURATION: Field = 0..2;
+}
+
+/// Start the gyroscope bias estimation, only valid in Measurement State.
+/// The device must not rotate for the duration of the estimation.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetNoRotation {
+    /// Duration of the estimation in seconds
+    pub duration: u16,
+}
+
+impl SetNoRotation {
+    pub const WIRE_SIZE: usize = 2;
+
+    pub fn new(duration: u16) -> Self {
+        SetNoRotation { duration }
+    }
+}
+
+impl MessageExt for SetNoRotation {
+    const MSG_ID: MessageId = MessageId::new(0x22);
+}
+
+impl MessageEncode for SetNoRotation {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        BigEndian::write_u16(&mut frame.payload_mut()?[field::DURATION], self.duration);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetNoRotation {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            let duration = BigEndian::read_u16(&payload[field::DURATION]);
+            Ok(SetNoRotation::new(duration))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetNoRotationAck;
+
+impl MessageExt for SetNoRotationAck {
+    const MSG_ID: MessageId = MessageId::new(0x23);
+}
+
+impl MessageEncode for SetNoRotationAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetNoRotationAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetNoRotationAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = [0_u8; 7];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        SetNoRotation::new(300).encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..6], &[0x22, 0x02, 0x01, 0x2C]);
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(SetNoRotation::decode(&f), Ok(SetNoRotation::new(300)));
+    }
+
+    #[test]
+    fn missing_duration() {
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x22, 0x01, 0x05, 0x00][..]);
+        assert_eq!(
+            SetNoRotation::decode(&f),
+            Err(FrameError::InvalidMessagePayload)
+        );
+    }
+}
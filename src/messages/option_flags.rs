@@ -0,0 +1,222 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use bitfield::bitfield;
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+bitfield! {
+    /// Contains the 32bit device option flags
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+    #[repr(transparent)]
+    pub struct OptionFlags(u32);
+    /// Disable automatic storing of the filter state
+    pub disable_auto_store, set_disable_auto_store : 0;
+    /// Start in Config State instead of Measurement State at power-on
+    pub disable_auto_measurement, set_disable_auto_measurement : 1;
+    /// Use BeiDou instead of GLONASS
+    pub enable_beidou, set_enable_beidou : 2;
+    /// Disable the GNSS receiver
+    pub disable_gps, set_disable_gps : 3;
+    /// Active Heading Stabilization
+    pub enable_ahs, set_enable_ahs : 4;
+    pub enable_orientation_smoother, set_enable_orientation_smoother : 5;
+    pub enable_configurable_bus_id, set_enable_configurable_bus_id : 6;
+    /// In-run Compass Calibration
+    pub enable_in_run_compass_calibration, set_enable_in_run_compass_calibration : 7;
+    /// Don't send a Configuration message at power-on
+    pub disable_config_message_at_startup, set_disable_config_message_at_startup : 8;
+    pub enable_cold_filter_resets, set_enable_cold_filter_resets : 9;
+    pub enable_position_velocity_smoother, set_enable_position_velocity_smoother : 10;
+    /// Continuous Zero Rotation Update
+    pub enable_continuous_zru, set_enable_continuous_zru : 11;
+}
+
+impl fmt::Display for OptionFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "OptionFlags(0x{:08X}: AHS({}), ICC({}), OrientationSmoother({}), BeiDou({}), ...)",
+            self.0,
+            self.enable_ahs(),
+            self.enable_in_run_compass_calibration(),
+            self.enable_orientation_smoother(),
+            self.enable_beidou()
+        )
+    }
+}
+
+impl OptionFlags {
+    pub const WIRE_SIZE: usize = 4;
+}
+
+mod field {
+    use crate::wire::{field32, Field};
+
+    pub const FLAGS: Field = field32::F0;
+    pub const SET: Field = field32::F0;
+    pub const CLEAR: Field = field32::F1;
+}
+
+/// Request the active option flags
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqOptionFlags;
+
+impl MessageExt for ReqOptionFlags {
+    const MSG_ID: MessageId = MessageId::new(0x48);
+}
+
+impl MessageEncode for ReqOptionFlags {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqOptionFlags {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqOptionFlags)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqOptionFlagsAck(pub OptionFlags);
+
+impl MessageExt for ReqOptionFlagsAck {
+    const MSG_ID: MessageId = MessageId::new(0x49);
+}
+
+impl MessageEncode for ReqOptionFlagsAck {
+    const MAX_PAYLOAD_LEN: usize = OptionFlags::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(OptionFlags::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        BigEndian::write_u32(&mut frame.payload_mut()?[field::FLAGS], self.0 .0);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqOptionFlagsAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < OptionFlags::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            let flags = BigEndian::read_u32(&payload[field::FLAGS]);
+            Ok(ReqOptionFlagsAck(OptionFlags(flags)))
+        }
+    }
+}
+
+/// Set and clear option flags, only valid in Config State.
+/// Flags that are in neither `set` nor `clear` are left unchanged.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetOptionFlags {
+    pub set: OptionFlags,
+    pub clear: OptionFlags,
+}
+
+impl SetOptionFlags {
+    pub const WIRE_SIZE: usize = 2 * OptionFlags::WIRE_SIZE;
+}
+
+impl MessageExt for SetOptionFlags {
+    const MSG_ID: MessageId = MessageId::new(0x48);
+}
+
+impl MessageEncode for SetOptionFlags {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        BigEndian::write_u32(&mut payload[field::SET], self.set.0);
+        BigEndian::write_u32(&mut payload[field::CLEAR], self.clear.0);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetOptionFlags {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(SetOptionFlags {
+                set: OptionFlags(BigEndian::read_u32(&payload[field::SET])),
+                clear: OptionFlags(BigEndian::read_u32(&payload[field::CLEAR])),
+            })
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetOptionFlagsAck;
+
+impl MessageExt for SetOptionFlagsAck {
+    const MSG_ID: MessageId = MessageId::new(0x49);
+}
+
+impl MessageEncode for SetOptionFlagsAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetOptionFlagsAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetOptionFlagsAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn flag_masks() {
+        type Getter = fn(&OptionFlags) -> bool;
+        let flags: [(Getter, u32); 12] = [
+            (OptionFlags::disable_auto_store, 0x0001),
+            (OptionFlags::disable_auto_measurement, 0x0002),
+            (OptionFlags::enable_beidou, 0x0004),
+            (OptionFlags::disable_gps, 0x0008),
+            (OptionFlags::enable_ahs, 0x0010),
+            (OptionFlags::enable_orientation_smoother, 0x0020),
+            (OptionFlags::enable_configurable_bus_id, 0x0040),
+            (OptionFlags::enable_in_run_compass_calibration, 0x0080),
+            (OptionFlags::disable_config_message_at_startup, 0x0100),
+            (OptionFlags::enable_cold_filter_resets, 0x0200),
+            (OptionFlags::enable_position_velocity_smoother, 0x0400),
+            (OptionFlags::enable_continuous_zru, 0x0800),
+        ];
+        for (get, mask) in flags.iter() {
+            assert!(get(&OptionFlags(*mask)));
+            assert!(!get(&OptionFlags(!*mask)));
+        }
+    }
+
+    #[test]
+    fn encode_set_clear() {
+        let mut set = OptionFlags::default();
+        set.set_enable_ahs(true);
+        set.set_enable_in_run_compass_calibration(true);
+        let mut clear = OptionFlags::default();
+        clear.set_enable_beidou(true);
+        let mut bytes = [0_u8; 13];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        SetOptionFlags { set, clear }.encode(&mut f).unwrap();
+        assert_eq!(
+            &bytes[2..12],
+            &[0x48, 0x08, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0x04]
+        );
+    }
+}
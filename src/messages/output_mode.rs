@@ -0,0 +1,353 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    wire::Precision,
+};
+use bitfield::bitfield;
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+bitfield! {
+    /// Selects the data contained in a legacy [`MTDataLegacy`](crate::messages::MTDataLegacy)
+    /// message
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+    #[repr(transparent)]
+    pub struct OutputMode(u16);
+    pub temperature, set_temperature : 0;
+    /// Calibrated acceleration, rate of turn and magnetic field
+    pub calibrated, set_calibrated : 1;
+    pub orientation, set_orientation : 2;
+    /// Auxiliary analog inputs
+    pub auxiliary, set_auxiliary : 3;
+    pub position, set_position : 4;
+    pub velocity, set_velocity : 5;
+    pub status, set_status : 11;
+    pub raw_gps, set_raw_gps : 12;
+    pub raw_inertial, set_raw_inertial : 14;
+}
+
+impl OutputMode {
+    pub const WIRE_SIZE: usize = 2;
+}
+
+impl fmt::Display for OutputMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "OutputMode(0x{:04X}: Temp({}), Calib({}), Orient({}), Status({}), ...)",
+            self.0,
+            self.temperature(),
+            self.calibrated(),
+            self.orientation(),
+            self.status()
+        )
+    }
+}
+
+enum_with_unknown! {
+    /// Orientation output representation of the legacy output format
+    pub enum OrientationMode(u8) {
+        Quaternion = 0,
+        EulerAngles = 1,
+        Matrix = 2,
+    }
+}
+
+bitfield! {
+    /// Settings of the data contained in a legacy [`MTDataLegacy`](crate::messages::MTDataLegacy)
+    /// message
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+    #[repr(transparent)]
+    pub struct OutputSettings(u32);
+    pub sample_counter, set_sample_counter : 0;
+    pub utc_time, set_utc_time : 1;
+    u8, orientation_mode_code, set_orientation_mode_code : 3, 2;
+    pub disable_acceleration, set_disable_acceleration : 4;
+    pub disable_rate_of_turn, set_disable_rate_of_turn : 5;
+    pub disable_magnetic_field, set_disable_magnetic_field : 6;
+    u8, data_format_code, set_data_format_code : 9, 8;
+    pub disable_aux1, set_disable_aux1 : 10;
+    pub disable_aux2, set_disable_aux2 : 11;
+    /// Output in the North East Down coordinate system instead of North West Up
+    pub ned, set_ned : 31;
+}
+
+impl OutputSettings {
+    pub const WIRE_SIZE: usize = 4;
+
+    pub fn orientation_mode(&self) -> OrientationMode {
+        self.orientation_mode_code().into()
+    }
+
+    pub fn set_orientation_mode(&mut self, value: OrientationMode) {
+        self.set_orientation_mode_code(value.into())
+    }
+
+    /// The precision of the calibrated data, orientation, position and velocity fields
+    pub fn data_format(&self) -> Precision {
+        self.data_format_code().into()
+    }
+
+    pub fn set_data_format(&mut self, value: Precision) {
+        self.set_data_format_code(value.into())
+    }
+}
+
+impl fmt::Display for OutputSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "OutputSettings(0x{:08X}: {:?}, {:?}, SampleCounter({}), UTC({}), ...)",
+            self.0,
+            self.orientation_mode(),
+            self.data_format(),
+            self.sample_counter(),
+            self.utc_time()
+        )
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const MODE: Field = 0..2;
+    pub const SETTINGS: Field = 0..4;
+}
+
+/// Request the legacy output mode
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqOutputMode;
+
+impl MessageExt for ReqOutputMode {
+    const MSG_ID: MessageId = MessageId::new(0xD0);
+}
+
+impl MessageEncode for ReqOutputMode {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqOutputMode {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqOutputMode)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqOutputModeAck(pub OutputMode);
+
+impl MessageExt for ReqOutputModeAck {
+    const MSG_ID: MessageId = MessageId::new(0xD1);
+}
+
+impl MessageEncode for ReqOutputModeAck {
+    const MAX_PAYLOAD_LEN: usize = OutputMode::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_output_mode(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for ReqOutputModeAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_output_mode(frame).map(ReqOutputModeAck)
+    }
+}
+
+/// Set the legacy output mode, only valid in Config State
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetOutputMode(pub OutputMode);
+
+impl MessageExt for SetOutputMode {
+    const MSG_ID: MessageId = MessageId::new(0xD0);
+}
+
+impl MessageEncode for SetOutputMode {
+    const MAX_PAYLOAD_LEN: usize = OutputMode::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_output_mode(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for SetOutputMode {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_output_mode(frame).map(SetOutputMode)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetOutputModeAck;
+
+impl MessageExt for SetOutputModeAck {
+    const MSG_ID: MessageId = MessageId::new(0xD1);
+}
+
+impl MessageEncode for SetOutputModeAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetOutputModeAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetOutputModeAck)
+    }
+}
+
+fn encode_output_mode(mode: OutputMode, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+    frame.set_payload_length(PayloadLength::Standard(OutputMode::WIRE_SIZE as u8));
+    frame.check_payload_length()?;
+    BigEndian::write_u16(&mut frame.payload_mut()?[field::MODE], mode.0);
+    Ok(())
+}
+
+fn decode_output_mode(frame: &Frame<&[u8]>) -> Result<OutputMode, FrameError> {
+    let payload = frame.payload()?;
+    if payload.len() < OutputMode::WIRE_SIZE {
+        Err(FrameError::InvalidMessagePayload)
+    } else {
+        Ok(OutputMode(BigEndian::read_u16(&payload[field::MODE])))
+    }
+}
+
+/// Request the legacy output settings
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqOutputSettings;
+
+impl MessageExt for ReqOutputSettings {
+    const MSG_ID: MessageId = MessageId::new(0xD2);
+}
+
+impl MessageEncode for ReqOutputSettings {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqOutputSettings {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqOutputSettings)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqOutputSettingsAck(pub OutputSettings);
+
+impl MessageExt for ReqOutputSettingsAck {
+    const MSG_ID: MessageId = MessageId::new(0xD3);
+}
+
+impl MessageEncode for ReqOutputSettingsAck {
+    const MAX_PAYLOAD_LEN: usize = OutputSettings::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_output_settings(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for ReqOutputSettingsAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_output_settings(frame).map(ReqOutputSettingsAck)
+    }
+}
+
+/// Set the legacy output settings, only valid in Config State
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetOutputSettings(pub OutputSettings);
+
+impl MessageExt for SetOutputSettings {
+    const MSG_ID: MessageId = MessageId::new(0xD2);
+}
+
+impl MessageEncode for SetOutputSettings {
+    const MAX_PAYLOAD_LEN: usize = OutputSettings::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_output_settings(self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for SetOutputSettings {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_output_settings(frame).map(SetOutputSettings)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetOutputSettingsAck;
+
+impl MessageExt for SetOutputSettingsAck {
+    const MSG_ID: MessageId = MessageId::new(0xD3);
+}
+
+impl MessageEncode for SetOutputSettingsAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetOutputSettingsAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetOutputSettingsAck)
+    }
+}
+
+fn encode_output_settings(
+    settings: OutputSettings,
+    frame: &mut Frame<&mut [u8]>,
+) -> Result<(), FrameError> {
+    frame.set_payload_length(PayloadLength::Standard(OutputSettings::WIRE_SIZE as u8));
+    frame.check_payload_length()?;
+    BigEndian::write_u32(&mut frame.payload_mut()?[field::SETTINGS], settings.0);
+    Ok(())
+}
+
+fn decode_output_settings(frame: &Frame<&[u8]>) -> Result<OutputSettings, FrameError> {
+    let payload = frame.payload()?;
+    if payload.len() < OutputSettings::WIRE_SIZE {
+        Err(FrameError::InvalidMessagePayload)
+    } else {
+        Ok(OutputSettings(BigEndian::read_u32(
+            &payload[field::SETTINGS],
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn settings_fields() {
+        let mut s = OutputSettings::default();
+        s.set_sample_counter(true);
+        s.set_orientation_mode(OrientationMode::EulerAngles);
+        s.set_data_format(Precision::Float64);
+        s.set_disable_magnetic_field(true);
+        assert_eq!(s.0, 0x0000_0345);
+        assert_eq!(s.orientation_mode(), OrientationMode::EulerAngles);
+        assert_eq!(s.data_format(), Precision::Float64);
+
+        let mut bytes = [0_u8; 9];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        SetOutputSettings(s).encode(&mut f).unwrap();
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(SetOutputSettings::decode(&f), Ok(SetOutputSettings(s)));
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    messages::BaudRate,
+};
+use bitfield::bitfield;
+use byteorder::{BigEndian, ByteOrder};
+use core::{cmp, fmt};
+
+bitfield! {
+    /// The 32bit configuration of a single device port
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+    #[repr(transparent)]
+    pub struct PortConfig(u32);
+    u8, baud_rate_code, set_baud_rate_code : 7, 0;
+    /// Hardware (RTS/CTS) flow control
+    pub flow_control, set_flow_control : 8;
+    /// The protocol used on the port
+    pub u8, protocol, set_protocol : 15, 12;
+}
+
+impl PortConfig {
+    pub const WIRE_SIZE: usize = 4;
+
+    pub fn baud_rate(&self) -> BaudRate {
+        self.baud_rate_code().into()
+    }
+
+    pub fn set_baud_rate(&mut self, value: BaudRate) {
+        self.set_baud_rate_code(value.into())
+    }
+}
+
+impl fmt::Display for PortConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PortConfig(BaudRate({}), FlowControl({}), Protocol({}))",
+            self.baud_rate(),
+            self.flow_control(),
+            self.protocol()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PortConfigIterator<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> PortConfigIterator<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        PortConfigIterator { buffer }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = PortConfig> + 'a {
+        self.buffer
+            .chunks_exact(PortConfig::WIRE_SIZE)
+            .map(|chunk| PortConfig(BigEndian::read_u32(chunk)))
+    }
+}
+
+/// Request the configuration of each of the device's ports
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqPortConfig;
+
+impl MessageExt for ReqPortConfig {
+    const MSG_ID: MessageId = MessageId::new(0x8C);
+}
+
+impl MessageEncode for ReqPortConfig {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqPortConfig {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqPortConfig)
+    }
+}
+
+/// The configuration of each of the device's ports, in port order
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqPortConfigAck<'a>(pub PortConfigIterator<'a>);
+
+impl<'a> MessageExt for ReqPortConfigAck<'a> {
+    const MSG_ID: MessageId = MessageId::new(0x8D);
+}
+
+impl<'a> MessageDecode<'a> for ReqPortConfigAck<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        Ok(ReqPortConfigAck(PortConfigIterator::new(payload)))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TooManyPortConfigs;
+
+/// Set the configuration of each of the device's ports, in port order.
+/// Only valid in Config State, the new configuration is applied after a reset of the device.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetPortConfig<T: AsRef<[PortConfig]>> {
+    ports: T,
+}
+
+impl<T: AsRef<[PortConfig]>> MessageExt for SetPortConfig<T> {
+    const MSG_ID: MessageId = MessageId::new(0x8C);
+}
+
+impl<T: AsRef<[PortConfig]>> SetPortConfig<T> {
+    /// The most port configurations that fit in a standard length payload
+    pub const MAX_SETTINGS: usize = 63;
+
+    pub fn new_unchecked(ports: T) -> Self {
+        SetPortConfig { ports }
+    }
+
+    pub fn new(ports: T) -> Result<Self, TooManyPortConfigs> {
+        let m = Self::new_unchecked(ports);
+        m.check_len()?;
+        Ok(m)
+    }
+
+    pub fn check_len(&self) -> Result<(), TooManyPortConfigs> {
+        let len = self.ports.as_ref().len();
+        if len > Self::MAX_SETTINGS {
+            Err(TooManyPortConfigs)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: AsRef<[PortConfig]>> MessageEncode for SetPortConfig<T> {
+    const MAX_PAYLOAD_LEN: usize = Self::MAX_SETTINGS * PortConfig::WIRE_SIZE;
+
+    fn payload_len(&self) -> usize {
+        cmp::min(Self::MAX_SETTINGS, self.ports.as_ref().len()) * PortConfig::WIRE_SIZE
+    }
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        let ports = self.ports.as_ref();
+        frame.set_payload_length(
+            PayloadLength::new(self.payload_len()).ok_or(FrameError::InvalidPayloadLength)?,
+        );
+        frame.check_payload_length()?;
+        for (src, dst) in ports
+            .iter()
+            .zip(frame.payload_mut()?.chunks_exact_mut(PortConfig::WIRE_SIZE))
+        {
+            BigEndian::write_u32(dst, src.0);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetPortConfigAck;
+
+impl MessageExt for SetPortConfigAck {
+    const MSG_ID: MessageId = MessageId::new(0x8D);
+}
+
+impl MessageEncode for SetPortConfigAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetPortConfigAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetPortConfigAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static ACK_MSG: [u8; 13] = [
+        0xFA, 0xFF, 0x8D, 0x08,
+        0x00, 0x00, 0x11, 0x0A,
+        0x00, 0x00, 0x00, 0x02,
+        0x00,
+    ];
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new_unchecked(&ACK_MSG[..]);
+        let msg = ReqPortConfigAck::decode(&f).unwrap();
+        let mut iter = msg.0.iter();
+        let p = iter.next().unwrap();
+        assert_eq!(p.baud_rate(), BaudRate::Baud921600);
+        assert!(p.flow_control());
+        assert_eq!(p.protocol(), 1);
+        let p = iter.next().unwrap();
+        assert_eq!(p.baud_rate(), BaudRate::Baud115200);
+        assert!(!p.flow_control());
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn encode() {
+        let mut p = PortConfig::default();
+        p.set_baud_rate(BaudRate::Baud921600);
+        p.set_flow_control(true);
+        p.set_protocol(1);
+        let ports = [p, PortConfig(0x02)];
+        let mut bytes = [0_u8; 13];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        SetPortConfig::new(&ports[..])
+            .unwrap()
+            .encode(&mut f)
+            .unwrap();
+        assert_eq!(
+            &bytes[2..12],
+            &[0x8C, 0x08, 0x00, 0x00, 0x11, 0x0A, 0x00, 0x00, 0x00, 0x02]
+        );
+    }
+
+    #[test]
+    fn too_many_ports() {
+        let ports = [PortConfig(0); SetPortConfig::<[PortConfig; 0]>::MAX_SETTINGS + 1];
+        assert_eq!(
+            SetPortConfig::new(&ports[..]).unwrap_err(),
+            TooManyPortConfigs
+        );
+        assert!(SetPortConfig::new(&ports[1..]).is_ok());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    wire::ascii_field,
+};
+use core::{cmp, fmt};
+
+/// Request the product code, i.e. "MTi-G-710-2A8G4"
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqProductCode;
+
+impl MessageExt for ReqProductCode {
+    const MSG_ID: MessageId = MessageId::new(0x1C);
+}
+
+impl MessageEncode for ReqProductCode {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqProductCode {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqProductCode)
+    }
+}
+
+/// The product code in ASCII, trailing padding removed
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ProductCode<'a>(pub &'a str);
+
+impl<'a> ProductCode<'a> {
+    /// Max length of the product code field
+    pub const MAX_WIRE_SIZE: usize = 20;
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> fmt::Display for ProductCode<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl<'a> MessageExt for ProductCode<'a> {
+    const MSG_ID: MessageId = MessageId::new(0x1D);
+}
+
+impl<'a> MessageEncode for ProductCode<'a> {
+    const MAX_PAYLOAD_LEN: usize = Self::MAX_WIRE_SIZE;
+
+    fn payload_len(&self) -> usize {
+        cmp::min(Self::MAX_WIRE_SIZE, self.0.len())
+    }
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        let code = self.0.as_bytes();
+        if code.len() > Self::MAX_WIRE_SIZE {
+            return Err(FrameError::InvalidMessagePayload);
+        }
+        frame.set_payload_length(PayloadLength::Standard(self.payload_len() as u8));
+        frame.check_payload_length()?;
+        frame.payload_mut()?.copy_from_slice(code);
+        Ok(())
+    }
+}
+
+impl<'a> MessageDecode<'a> for ProductCode<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        Ok(ProductCode(ascii_field(payload)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 25] = [
+        0xFA, 0xFF, 0x1D, 0x14,
+        b'M', b'T', b'i', b'-', b'3', b'0', b'-', b'2', b'A', b'5', b'G', b'4',
+        b' ', b' ', b' ', b' ', 0x00, 0x00, 0x00, 0x00,
+        0x00,
+    ];
+
+    #[test]
+    fn decode_padded() {
+        let f = Frame::new_unchecked(&MSG[..]);
+        let code = ProductCode::decode(&f).unwrap();
+        assert_eq!(code.as_str(), "MTi-30-2A5G4");
+    }
+}
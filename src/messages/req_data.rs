@@ -0,0 +1,55 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+
+/// Request a single data sample, only valid in Measurement State.
+///
+/// The device responds with an [`MTData2`](crate::messages::MTData2) message
+/// containing the configured output, see [`DataPoller`](crate::data_poller::DataPoller).
+/// Polling is typically used with an output frequency of 0xFFFF, otherwise the device
+/// also keeps streaming at the configured rates.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqData;
+
+impl MessageExt for ReqData {
+    const MSG_ID: MessageId = MessageId::new(0x34);
+}
+
+impl MessageEncode for ReqData {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqData {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::encode_frame;
+    use crate::message::BusId;
+    use pretty_assertions::assert_eq;
+
+    static MSG: [u8; 5] = [0xFA, 0xFF, 0x34, 0x00, 0xCD];
+
+    #[test]
+    fn encode() {
+        let mut buffer = [0_u8; Frame::<&[u8]>::buffer_len(ReqData::MAX_PAYLOAD_LEN)];
+        assert_eq!(encode_frame(&ReqData, BusId::MASTER, &mut buffer), Ok(5));
+        assert_eq!(buffer, MSG);
+    }
+
+    #[test]
+    fn decode() {
+        let f = Frame::new(&MSG[..]).unwrap();
+        assert_eq!(f.message_id(), ReqData::MSG_ID);
+        assert_eq!(ReqData::decode(&f), Ok(ReqData));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+
+/// Reset the device.
+/// The device will send a WakeUp message after the reset is complete.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Reset;
+
+impl MessageExt for Reset {
+    const MSG_ID: MessageId = MessageId::new(0x40);
+}
+
+impl MessageEncode for Reset {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for Reset {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(Reset)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ResetAck;
+
+impl MessageExt for ResetAck {
+    const MSG_ID: MessageId = MessageId::new(0x41);
+}
+
+impl MessageEncode for ResetAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ResetAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ResetAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::encode_frame;
+    use crate::message::BusId;
+    use pretty_assertions::assert_eq;
+
+    static MSG: [u8; 5] = [0xFA, 0xFF, 0x40, 0x00, 0xC1];
+    static ACK_MSG: [u8; 5] = [0xFA, 0xFF, 0x41, 0x00, 0xC0];
+
+    #[test]
+    fn encode() {
+        let mut bytes = [0_u8; 5];
+        assert_eq!(encode_frame(&Reset, BusId::MASTER, &mut bytes), Ok(5));
+        assert_eq!(bytes, MSG);
+    }
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new(&ACK_MSG[..]).unwrap();
+        assert_eq!(f.message_id(), ResetAck::MSG_ID);
+        assert_eq!(ResetAck::decode(&f), Ok(ResetAck));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+
+enum_with_unknown! {
+    pub enum OrientationResetCode(u16) {
+        /// Store the current heading and inclination reset settings, only valid in Config State
+        StoreCurrentSettings = 0x0000,
+        HeadingReset = 0x0001,
+        InclinationReset = 0x0003,
+        /// Heading and inclination reset
+        AlignmentReset = 0x0004,
+        /// Revert the heading to the default orientation
+        DefaultHeading = 0x0005,
+        /// Revert the inclination to the default orientation
+        DefaultInclination = 0x0006,
+        /// Revert the heading and inclination to the default orientation
+        DefaultAlignment = 0x0007,
+    }
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const CODE: Field = 0..2;
+}
+
+/// Reset the orientation output of the device.
+/// Heading and inclination resets are only valid in Measurement State.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ResetOrientation {
+    pub code: OrientationResetCode,
+}
+
+impl ResetOrientation {
+    pub const WIRE_SIZE: usize = 2;
+
+    pub fn new(code: OrientationResetCode) -> Self {
+        ResetOrientation { code }
+    }
+}
+
+impl MessageExt for ResetOrientation {
+    const MSG_ID: MessageId = MessageId::new(0xA4);
+}
+
+impl MessageEncode for ResetOrientation {
+    const MAX_PAYLOAD_LEN: usize = Self::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(Self::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        BigEndian::write_u16(&mut frame.payload_mut()?[field::CODE], self.code.into());
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ResetOrientation {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < Self::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            let code = BigEndian::read_u16(&payload[field::CODE]);
+            Ok(ResetOrientation::new(code.into()))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ResetOrientationAck;
+
+impl MessageExt for ResetOrientationAck {
+    const MSG_ID: MessageId = MessageId::new(0xA5);
+}
+
+impl MessageEncode for ResetOrientationAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ResetOrientationAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ResetOrientationAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let codes = [
+            (OrientationResetCode::StoreCurrentSettings, 0x0000),
+            (OrientationResetCode::HeadingReset, 0x0001),
+            (OrientationResetCode::InclinationReset, 0x0003),
+            (OrientationResetCode::AlignmentReset, 0x0004),
+            (OrientationResetCode::DefaultHeading, 0x0005),
+            (OrientationResetCode::DefaultInclination, 0x0006),
+            (OrientationResetCode::DefaultAlignment, 0x0007),
+            (OrientationResetCode::Unknown(0x0002), 0x0002),
+        ];
+        for (code, raw) in codes.iter() {
+            let mut bytes = [0_u8; 7];
+            let mut f = Frame::new_unchecked(&mut bytes[..]);
+            ResetOrientation::new(*code).encode(&mut f).unwrap();
+            assert_eq!(&bytes[2..6], &[0xA4, 0x02, 0x00, *raw]);
+            let f = Frame::new_unchecked(&bytes[..]);
+            assert_eq!(
+                ResetOrientation::decode(&f),
+                Ok(ResetOrientation::new(*code))
+            );
+        }
+    }
+
+    #[test]
+    fn missing_code() {
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0xA4, 0x00, 0x5D][..]);
+        assert_eq!(
+            ResetOrientation::decode(&f),
+            Err(FrameError::InvalidMessagePayload)
+        );
+    }
+}
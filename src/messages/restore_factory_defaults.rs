@@ -0,0 +1,80 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+
+/// Restore all settings of the device to the factory defaults.
+/// Only valid in Config State.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RestoreFactoryDefaults;
+
+impl MessageExt for RestoreFactoryDefaults {
+    const MSG_ID: MessageId = MessageId::new(0x0E);
+}
+
+impl MessageEncode for RestoreFactoryDefaults {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for RestoreFactoryDefaults {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(RestoreFactoryDefaults)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RestoreFactoryDefaultsAck;
+
+impl MessageExt for RestoreFactoryDefaultsAck {
+    const MSG_ID: MessageId = MessageId::new(0x0F);
+}
+
+impl MessageEncode for RestoreFactoryDefaultsAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for RestoreFactoryDefaultsAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(RestoreFactoryDefaultsAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::encode_frame;
+    use crate::message::BusId;
+    use pretty_assertions::assert_eq;
+
+    static MSG: [u8; 5] = [0xFA, 0xFF, 0x0E, 0x00, 0xF3];
+    static ACK_MSG: [u8; 5] = [0xFA, 0xFF, 0x0F, 0x00, 0xF2];
+
+    #[test]
+    fn encode() {
+        let mut bytes = [0_u8; 5];
+        assert_eq!(
+            encode_frame(&RestoreFactoryDefaults, BusId::MASTER, &mut bytes),
+            Ok(5)
+        );
+        assert_eq!(bytes, MSG);
+    }
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new(&ACK_MSG[..]).unwrap();
+        assert_eq!(f.message_id(), RestoreFactoryDefaultsAck::MSG_ID);
+        assert_eq!(
+            RestoreFactoryDefaultsAck::decode(&f),
+            Ok(RestoreFactoryDefaultsAck)
+        );
+    }
+}
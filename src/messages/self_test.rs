@@ -0,0 +1,135 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use bitfield::bitfield;
+use byteorder::{BigEndian, ByteOrder};
+use core::fmt;
+
+/// Run the built-in self-test.
+/// The device responds with [`SelfTestResults`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RunSelfTest;
+
+impl MessageExt for RunSelfTest {
+    const MSG_ID: MessageId = MessageId::new(0x24);
+}
+
+impl MessageEncode for RunSelfTest {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for RunSelfTest {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(RunSelfTest)
+    }
+}
+
+bitfield! {
+    /// Contains the 16bit self-test result flags, a set bit indicates the test passed
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    #[repr(transparent)]
+    pub struct SelfTestFlags(u16);
+    pub acc_x, set_acc_x : 0;
+    pub acc_y, set_acc_y : 1;
+    pub acc_z, set_acc_z : 2;
+    pub gyr_x, set_gyr_x : 3;
+    pub gyr_y, set_gyr_y : 4;
+    pub gyr_z, set_gyr_z : 5;
+    pub mag_x, set_mag_x : 6;
+    pub mag_y, set_mag_y : 7;
+    pub mag_z, set_mag_z : 8;
+    /// Barometer
+    pub baro, set_baro : 9;
+    /// GNSS receiver
+    pub gnss, set_gnss : 10;
+    pub battery, set_battery : 11;
+    pub flash, set_flash : 12;
+    pub button, set_button : 13;
+    pub sync, set_sync : 14;
+}
+
+impl SelfTestFlags {
+    pub const WIRE_SIZE: usize = 2;
+
+    pub fn accelerometer_passed(&self) -> bool {
+        self.acc_x() && self.acc_y() && self.acc_z()
+    }
+
+    pub fn gyroscope_passed(&self) -> bool {
+        self.gyr_x() && self.gyr_y() && self.gyr_z()
+    }
+
+    pub fn magnetometer_passed(&self) -> bool {
+        self.mag_x() && self.mag_y() && self.mag_z()
+    }
+}
+
+impl fmt::Display for SelfTestFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SelfTest(0x{:04X}: Acc({}), Gyr({}), Mag({}), Baro({}), GNSS({}), Battery({}))",
+            self.0,
+            self.accelerometer_passed(),
+            self.gyroscope_passed(),
+            self.magnetometer_passed(),
+            self.baro(),
+            self.gnss(),
+            self.battery()
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SelfTestResults(pub SelfTestFlags);
+
+impl MessageExt for SelfTestResults {
+    const MSG_ID: MessageId = MessageId::new(0x25);
+}
+
+impl MessageEncode for SelfTestResults {
+    const MAX_PAYLOAD_LEN: usize = SelfTestFlags::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(SelfTestFlags::WIRE_SIZE as u8));
+        frame.check_payload_length()?;
+        BigEndian::write_u16(frame.payload_mut()?, self.0 .0);
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SelfTestResults {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < SelfTestFlags::WIRE_SIZE {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(SelfTestResults(SelfTestFlags(BigEndian::read_u16(payload))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    static MSG: [u8; 7] = [0xFA, 0xFF, 0x25, 0x02, 0x01, 0xDF, 0xFA];
+
+    #[test]
+    fn decode() {
+        let f = Frame::new(&MSG[..]).unwrap();
+        let r = SelfTestResults::decode(&f).unwrap().0;
+        assert!(r.accelerometer_passed());
+        assert!(!r.gyroscope_passed());
+        assert!(!r.gyr_z());
+        assert!(r.magnetometer_passed());
+        assert!(!r.baro());
+        assert_eq!(r.0, 0x01DF);
+    }
+}
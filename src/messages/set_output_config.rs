@@ -9,6 +9,30 @@ use core::cmp;
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct TooManyOutputConifgurations;
 
+/// Request the output configuration currently stored on the device.
+/// The device responds with a [`ReqOutputConfigurationAck`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqOutputConfiguration;
+
+impl MessageExt for ReqOutputConfiguration {
+    const MSG_ID: MessageId = MessageId::new(0xC0);
+}
+
+impl MessageEncode for ReqOutputConfiguration {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqOutputConfiguration {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqOutputConfiguration)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct SetOutputConfiguration<T: AsRef<[OutputConfiguration]>> {
     settings: T,
@@ -42,13 +66,18 @@ impl<T: AsRef<[OutputConfiguration]>> SetOutputConfiguration<T> {
 }
 
 impl<C: AsRef<[OutputConfiguration]>> MessageEncode for SetOutputConfiguration<C> {
+    const MAX_PAYLOAD_LEN: usize = Self::MAX_SETTINGS * WireOutputConfiguration::<&[u8]>::WIRE_SIZE;
+
+    fn payload_len(&self) -> usize {
+        cmp::min(Self::MAX_SETTINGS, self.settings.as_ref().len())
+            * WireOutputConfiguration::<&[u8]>::WIRE_SIZE
+    }
+
     fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
         let settings = self.settings.as_ref();
         let config_size = WireOutputConfiguration::<&[u8]>::WIRE_SIZE;
-        let num_settings = cmp::min(Self::MAX_SETTINGS, settings.len());
-        let min_size = config_size * num_settings;
         frame.set_payload_length(
-            PayloadLength::new(min_size).ok_or(FrameError::InvalidPayloadLength)?,
+            PayloadLength::new(self.payload_len()).ok_or(FrameError::InvalidPayloadLength)?,
         );
         frame.check_payload_length()?;
         for (src, dst) in settings
@@ -77,3 +106,53 @@ impl<'a> MessageDecode<'a> for SetOutputConfigurationAck<'a> {
         ))
     }
 }
+
+/// The acknowledgement of a [`ReqOutputConfiguration`] has the same layout as
+/// the acknowledgement of a [`SetOutputConfiguration`]
+pub type ReqOutputConfigurationAck<'a> = SetOutputConfigurationAck<'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wire::{DataId, DataType, OutputFrequency};
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static ACK_MSG: [u8; 13] = [
+        0xFA, 0xFF, 0xC1, 0x08,
+        0x10, 0x20, 0xFF, 0xFF,
+        0x40, 0x20, 0x01, 0x90,
+        0x00,
+    ];
+
+    #[test]
+    fn encode_req() {
+        let mut bytes = [0_u8; 5];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        ReqOutputConfiguration.encode(&mut f).unwrap();
+        assert_eq!(f.message_id(), MessageId::new(0xC0));
+        assert_eq!(f.payload_length(), Ok(PayloadLength::Standard(0)));
+    }
+
+    #[test]
+    fn decode_req_ack() {
+        let f = Frame::new_unchecked(&ACK_MSG[..]);
+        let ack = ReqOutputConfigurationAck::decode(&f).unwrap();
+        let mut iter = ack.0.iter();
+        assert_eq!(
+            iter.next(),
+            Some(OutputConfiguration::new(
+                DataId::from_data_type(DataType::PacketCounter),
+                OutputFrequency::MAX,
+            ))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(OutputConfiguration::new(
+                DataId::from_data_type(DataType::Acceleration),
+                OutputFrequency(400),
+            ))
+        );
+        assert_eq!(iter.next(), None);
+    }
+}
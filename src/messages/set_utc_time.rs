@@ -0,0 +1,149 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    mtdata2::UtcTime,
+};
+
+fn encode_utc_time(time: &UtcTime, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+    frame.set_payload_length(PayloadLength::Standard(UtcTime::WIRE_SIZE as u8));
+    frame.check_payload_length()?;
+    time.to_be_slice(frame.payload_mut()?)
+        .map_err(|_| FrameError::InvalidMessagePayload)
+}
+
+fn decode_utc_time(frame: &Frame<&[u8]>) -> Result<UtcTime, FrameError> {
+    UtcTime::from_be_slice(frame.payload()?).map_err(|_| FrameError::InvalidMessagePayload)
+}
+
+/// Request the UTC time of the device
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqUtcTime;
+
+impl MessageExt for ReqUtcTime {
+    const MSG_ID: MessageId = MessageId::new(0x60);
+}
+
+impl MessageEncode for ReqUtcTime {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqUtcTime {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqUtcTime)
+    }
+}
+
+/// The UTC time of the device, in the same layout as the
+/// [`UtcTime`](crate::mtdata2::UtcTime) data packet
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqUtcTimeAck(pub UtcTime);
+
+impl MessageExt for ReqUtcTimeAck {
+    const MSG_ID: MessageId = MessageId::new(0x61);
+}
+
+impl MessageEncode for ReqUtcTimeAck {
+    const MAX_PAYLOAD_LEN: usize = UtcTime::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_utc_time(&self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for ReqUtcTimeAck {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_utc_time(frame).map(ReqUtcTimeAck)
+    }
+}
+
+/// Set the UTC time of the device, i.e. from the host clock
+/// on devices without a GNSS receiver
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetUtcTime(pub UtcTime);
+
+impl MessageExt for SetUtcTime {
+    const MSG_ID: MessageId = MessageId::new(0x60);
+}
+
+impl MessageEncode for SetUtcTime {
+    const MAX_PAYLOAD_LEN: usize = UtcTime::WIRE_SIZE;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        encode_utc_time(&self.0, frame)
+    }
+}
+
+impl MessageDecode<'_> for SetUtcTime {
+    fn decode_frame(frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        decode_utc_time(frame).map(SetUtcTime)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetUtcTimeAck;
+
+impl MessageExt for SetUtcTimeAck {
+    const MSG_ID: MessageId = MessageId::new(0x61);
+}
+
+impl MessageEncode for SetUtcTimeAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for SetUtcTimeAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(SetUtcTimeAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mtdata2::UtcTimeStatusFlags;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 17] = [
+        0xFA, 0xFF, 0x60, 0x0C,
+        0x1D, 0xCD, 0x65, 0x00,
+        0x07, 0xEA,
+        0x0A, 0x12, 0x0E, 0x1E, 0x2D,
+        0x07,
+        0x00,
+    ];
+
+    #[test]
+    fn round_trip() {
+        let f = Frame::new_unchecked(&MSG[..]);
+        let msg = SetUtcTime::decode(&f).unwrap();
+        assert_eq!(
+            msg,
+            SetUtcTime(UtcTime {
+                year: 2026,
+                month: 10,
+                day: 18,
+                hour: 14,
+                minute: 30,
+                second: 45,
+                ns: 500_000_000,
+                flags: UtcTimeStatusFlags(0x07),
+            })
+        );
+
+        let mut bytes = [0_u8; 17];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        msg.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..16], &MSG[2..16]);
+    }
+}
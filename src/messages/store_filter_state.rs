@@ -0,0 +1,77 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+
+/// Store the current filter state, i.e. the learned sensor biases, so it is used
+/// as the initial state after the next power-up
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct StoreFilterState;
+
+impl MessageExt for StoreFilterState {
+    const MSG_ID: MessageId = MessageId::new(0x8A);
+}
+
+impl MessageEncode for StoreFilterState {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for StoreFilterState {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(StoreFilterState)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct StoreFilterStateAck;
+
+impl MessageExt for StoreFilterStateAck {
+    const MSG_ID: MessageId = MessageId::new(0x8B);
+}
+
+impl MessageEncode for StoreFilterStateAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for StoreFilterStateAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(StoreFilterStateAck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::encode_frame;
+    use crate::message::BusId;
+    use pretty_assertions::assert_eq;
+
+    static MSG: [u8; 5] = [0xFA, 0xFF, 0x8A, 0x00, 0x77];
+    static ACK_MSG: [u8; 5] = [0xFA, 0xFF, 0x8B, 0x00, 0x76];
+
+    #[test]
+    fn encode() {
+        let mut bytes = [0_u8; 5];
+        assert_eq!(
+            encode_frame(&StoreFilterState, BusId::MASTER, &mut bytes),
+            Ok(5)
+        );
+        assert_eq!(bytes, MSG);
+    }
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new(&ACK_MSG[..]).unwrap();
+        assert_eq!(f.message_id(), StoreFilterStateAck::MSG_ID);
+        assert_eq!(StoreFilterStateAck::decode(&f), Ok(StoreFilterStateAck));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    message::{
+        Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+    },
+    wire::{SyncSetting, WireSyncSetting, WireSyncSettingIterator},
+};
+use core::cmp;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TooManySyncSettings;
+
+/// Request the sync settings currently stored on the device.
+/// The device responds with a [`ReqSyncSettingsAck`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReqSyncSettings;
+
+impl MessageExt for ReqSyncSettings {
+    const MSG_ID: MessageId = MessageId::new(0x2C);
+}
+
+impl MessageEncode for ReqSyncSettings {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for ReqSyncSettings {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(ReqSyncSettings)
+    }
+}
+
+/// Set the sync settings, only valid in Config State.
+/// Replaces all of the sync settings stored on the device.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetSyncSettings<T: AsRef<[SyncSetting]>> {
+    settings: T,
+}
+
+impl<T: AsRef<[SyncSetting]>> MessageExt for SetSyncSettings<T> {
+    const MSG_ID: MessageId = MessageId::new(0x2C);
+}
+
+impl<T: AsRef<[SyncSetting]>> SetSyncSettings<T> {
+    /// The most settings that fit in a standard length payload
+    pub const MAX_SETTINGS: usize = 21;
+
+    pub fn new_unchecked(settings: T) -> Self {
+        SetSyncSettings { settings }
+    }
+
+    pub fn new(settings: T) -> Result<Self, TooManySyncSettings> {
+        let m = Self::new_unchecked(settings);
+        m.check_len()?;
+        Ok(m)
+    }
+
+    pub fn check_len(&self) -> Result<(), TooManySyncSettings> {
+        let len = self.settings.as_ref().len();
+        if len > Self::MAX_SETTINGS {
+            Err(TooManySyncSettings)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: AsRef<[SyncSetting]>> MessageEncode for SetSyncSettings<T> {
+    const MAX_PAYLOAD_LEN: usize = Self::MAX_SETTINGS * WireSyncSetting::<&[u8]>::WIRE_SIZE;
+
+    fn payload_len(&self) -> usize {
+        cmp::min(Self::MAX_SETTINGS, self.settings.as_ref().len())
+            * WireSyncSetting::<&[u8]>::WIRE_SIZE
+    }
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        let settings = self.settings.as_ref();
+        let setting_size = WireSyncSetting::<&[u8]>::WIRE_SIZE;
+        frame.set_payload_length(
+            PayloadLength::new(self.payload_len()).ok_or(FrameError::InvalidPayloadLength)?,
+        );
+        frame.check_payload_length()?;
+        for (src, dst) in settings
+            .iter()
+            .zip(frame.payload_mut()?.chunks_exact_mut(setting_size))
+        {
+            let mut s = WireSyncSetting::new_unchecked(dst);
+            s.set_sync_setting(*src);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SetSyncSettingsAck<'a>(pub WireSyncSettingIterator<'a>);
+
+impl<'a> MessageExt for SetSyncSettingsAck<'a> {
+    const MSG_ID: MessageId = MessageId::new(0x2D);
+}
+
+impl<'a> MessageDecode<'a> for SetSyncSettingsAck<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        Ok(SetSyncSettingsAck(WireSyncSettingIterator::new(payload)))
+    }
+}
+
+/// The acknowledgement of a [`ReqSyncSettings`] has the same layout as
+/// the acknowledgement of a [`SetSyncSettings`]
+pub type ReqSyncSettingsAck<'a> = SetSyncSettingsAck<'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::encode_frame;
+    use crate::message::BusId;
+    use crate::wire::{SyncFunction, SyncLine, SyncPolarity};
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static MSG: [u8; 29] = [
+        0xFA, 0xFF, 0x2C, 0x18,
+        0x03, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x04, 0x0A, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+        0x00, 0x0A, 0x00, 0x00,
+        0x9E,
+    ];
+
+    #[rustfmt::skip]
+    static ACK_MSG: [u8; 29] = [
+        0xFA, 0xFF, 0x2D, 0x18,
+        0x03, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x04, 0x0A, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+        0x00, 0x0A, 0x00, 0x00,
+        0x9D,
+    ];
+
+    fn settings() -> [SyncSetting; 2] {
+        [
+            SyncSetting::new(
+                SyncFunction::TriggerIndication,
+                SyncLine::In1,
+                SyncPolarity::RisingEdge,
+            ),
+            SyncSetting {
+                skip_factor: 1,
+                pulse_width: 10,
+                ..SyncSetting::new(
+                    SyncFunction::IntervalTransitionMeasurement,
+                    SyncLine::Out1,
+                    SyncPolarity::FallingEdge,
+                )
+            },
+        ]
+    }
+
+    #[test]
+    fn encode() {
+        let settings = settings();
+        let msg = SetSyncSettings::new(&settings[..]).unwrap();
+        let mut bytes = [0_u8; 29];
+        assert_eq!(encode_frame(&msg, BusId::MASTER, &mut bytes), Ok(29));
+        assert_eq!(bytes, MSG);
+    }
+
+    #[test]
+    fn decode_ack() {
+        let f = Frame::new(&ACK_MSG[..]).unwrap();
+        let msg = ReqSyncSettingsAck::decode(&f).unwrap();
+        let mut iter = msg.0.iter();
+        let expected = settings();
+        assert_eq!(iter.next(), Some(expected[0]));
+        assert_eq!(iter.next(), Some(expected[1]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn too_many_settings() {
+        let settings = [settings()[0]; 22];
+        assert_eq!(
+            SetSyncSettings::new(&settings[..]).unwrap_err(),
+            TooManySyncSettings
+        );
+    }
+}
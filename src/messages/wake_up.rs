@@ -0,0 +1,51 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+
+/// Sent by the device at power-on or after a reset.
+/// If the device receives a WakeUpAck within 500 ms it will enter Config State,
+/// otherwise it will enter Measurement State.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct WakeUp;
+
+impl MessageExt for WakeUp {
+    const MSG_ID: MessageId = MessageId::new(0x3E);
+}
+
+impl MessageEncode for WakeUp {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for WakeUp {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(WakeUp)
+    }
+}
+
+/// Acknowledge a WakeUp message, keeping the device in Config State.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct WakeUpAck;
+
+impl MessageExt for WakeUpAck {
+    const MSG_ID: MessageId = MessageId::new(0x3F);
+}
+
+impl MessageEncode for WakeUpAck {
+    const MAX_PAYLOAD_LEN: usize = 0;
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        frame.set_payload_length(PayloadLength::Standard(0));
+        Ok(())
+    }
+}
+
+impl MessageDecode<'_> for WakeUpAck {
+    fn decode_frame(_frame: &Frame<&[u8]>) -> Result<Self, FrameError> {
+        Ok(WakeUpAck)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::message::{
+    Frame, FrameError, MessageDecode, MessageEncode, MessageExt, MessageId, PayloadLength,
+};
+use byteorder::{BigEndian, ByteOrder};
+use core::{cmp, fmt};
+
+enum_with_unknown! {
+    /// Warning codes reported by the device in a Warning message,
+    /// these are XsResultValues in the Xsens SDK
+    pub enum WarningCode(u32) {
+        /// Device error, the payload may contain an extended error code
+        DeviceError = 40,
+        /// The device generates more data than the bus communication can handle
+        DataOverflow = 41,
+        /// The sample buffer of the device was full during a communication outage
+        BufferOverflow = 42,
+        /// An external trigger is not behaving as expected
+        ExternalTriggerError = 43,
+        /// The sample stream detected an error in the ordering of sample data
+        SampleStreamError = 44,
+        /// A dip in the power supply was detected and recovered from
+        PowerDip = 45,
+        /// A current limiter has been activated, shutting down the device
+        PowerOvercurrent = 46,
+        /// The device temperature is above the safe operating range
+        Overheating = 47,
+        /// The battery level reached a low threshold
+        BatteryLow = 48,
+        /// The specified filter profile ID is not available or invalid
+        InvalidFilterProfile = 49,
+        /// The settings stored in the device are invalid
+        InvalidStoredSettings = 50,
+        /// The requested operation is not allowed
+        AccessDenied = 51,
+        /// An error occurred accessing a file on the device
+        FileError = 52,
+        /// The output configuration is not valid for the device
+        OutputConfigError = 53,
+    }
+}
+
+impl fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarningCode::Unknown(c) => write!(f, "Unknown(0x{:08X})", c),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// Sent by the device when a situation occurs that may impair its operation
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Warning<'a> {
+    pub code: WarningCode,
+    /// Optional additional data following the warning code
+    pub data: &'a [u8],
+}
+
+impl<'a> Warning<'a> {
+    pub fn new(code: WarningCode) -> Self {
+        Warning { code, data: &[] }
+    }
+}
+
+impl<'a> fmt::Display for Warning<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Warning({}), Len({})", self.code, self.data.len())
+    }
+}
+
+mod field {
+    use crate::wire::{Field, Rest};
+
+    pub const CODE: Field = 0..4;
+    pub const DATA: Rest = 4..;
+}
+
+impl<'a> MessageExt for Warning<'a> {
+    const MSG_ID: MessageId = MessageId::new(0x43);
+}
+
+impl<'a> MessageEncode for Warning<'a> {
+    const MAX_PAYLOAD_LEN: usize = PayloadLength::MAX_EXT as usize;
+
+    fn payload_len(&self) -> usize {
+        cmp::min(Self::MAX_PAYLOAD_LEN, field::DATA.start + self.data.len())
+    }
+
+    fn encode_frame(&self, frame: &mut Frame<&mut [u8]>) -> Result<(), FrameError> {
+        if field::DATA.start + self.data.len() > Self::MAX_PAYLOAD_LEN {
+            return Err(FrameError::InvalidPayloadLength);
+        }
+        frame.set_payload_length(
+            PayloadLength::new(self.payload_len()).ok_or(FrameError::InvalidPayloadLength)?,
+        );
+        frame.check_payload_length()?;
+        let payload = frame.payload_mut()?;
+        BigEndian::write_u32(&mut payload[field::CODE], self.code.into());
+        payload[field::DATA].copy_from_slice(self.data);
+        Ok(())
+    }
+}
+
+impl<'a> MessageDecode<'a> for Warning<'a> {
+    fn decode_frame(frame: &Frame<&'a [u8]>) -> Result<Self, FrameError> {
+        let payload = frame.payload()?;
+        if payload.len() < field::DATA.start {
+            Err(FrameError::InvalidMessagePayload)
+        } else {
+            Ok(Warning {
+                code: BigEndian::read_u32(&payload[field::CODE]).into(),
+                data: &payload[field::DATA],
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn decode() {
+        let f = Frame::new(&[0xFA, 0xFF, 0x43, 0x04, 0x00, 0x00, 0x00, 0x2F, 0x8B][..]).unwrap();
+        let w = Warning::decode(&f).unwrap();
+        assert_eq!(w, Warning::new(WarningCode::Overheating));
+        assert!(w.data.is_empty());
+    }
+
+    #[test]
+    fn decode_with_data() {
+        #[rustfmt::skip]
+        let bytes = [
+            0xFA, 0xFF, 0x43, 0x06,
+            0x00, 0x00, 0x00, 0x28,
+            0x12, 0x34,
+            0x00,
+        ];
+        let f = Frame::new_unchecked(&bytes[..]);
+        let w = Warning::decode(&f).unwrap();
+        assert_eq!(w.code, WarningCode::DeviceError);
+        assert_eq!(w.data, &[0x12, 0x34]);
+    }
+
+    #[test]
+    fn decode_unknown_code() {
+        let bytes = [0xFA, 0xFF, 0x43, 0x04, 0x00, 0x00, 0x01, 0x90, 0x00];
+        let f = Frame::new_unchecked(&bytes[..]);
+        let w = Warning::decode(&f).unwrap();
+        assert_eq!(w.code, WarningCode::Unknown(400));
+        assert_eq!(u32::from(w.code), 400);
+
+        let f = Frame::new_unchecked(&[0xFA, 0xFF, 0x43, 0x02, 0x00, 0x01, 0x00][..]);
+        assert_eq!(
+            Warning::decode(&f).unwrap_err(),
+            FrameError::InvalidMessagePayload
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let w = Warning {
+            code: WarningCode::BatteryLow,
+            data: &[0xAB],
+        };
+        let mut bytes = [0_u8; 10];
+        let mut f = Frame::new_unchecked(&mut bytes[..]);
+        w.encode(&mut f).unwrap();
+        assert_eq!(&bytes[2..9], &[0x43, 0x05, 0x00, 0x00, 0x00, 0x30, 0xAB]);
+        let f = Frame::new_unchecked(&bytes[..]);
+        assert_eq!(Warning::decode(&f), Ok(w));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::precision::PrecisionExt;
+use core::fmt;
+
+/// Contains the high rate acceleration vector in x, y, and z axes in m/s^2,
+/// output at rates up to 2000 Hz
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct AccelerationHr<T: PrecisionExt> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: PrecisionExt> fmt::Display for AccelerationHr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X({:.3}), Y({:.3}), Z({:.3})", self.x, self.y, self.z)
+    }
+}
+
+precision_float32_3field_wire_impl!(AccelerationHr, x, y, z);
+precision_float64_3field_wire_impl!(AccelerationHr, x, y, z);
+precision_fp1220_3field_wire_impl!(AccelerationHr, x, y, z);
+precision_fp1632_3field_wire_impl!(AccelerationHr, x, y, z);
+batch_decode_3field_impl!(AccelerationHr, AccelerationHR);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wire::{DataType, MTData2PacketIter, WireError};
+    use approx::assert_relative_eq;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static WIRE_BYTES: [u8; 50] = [
+        // AccelerationHR, Float32
+        0x40, 0x40, 0x0C, 0x3F, 0x80, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x41, 0x1C, 0xCC, 0xCD,
+        // PacketCounter
+        0x10, 0x20, 0x02, 0x01, 0x14,
+        // AccelerationHR, Float32
+        0x40, 0x40, 0x0C, 0xBF, 0x80, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x41, 0x1C, 0xCC, 0xCD,
+        // AccelerationHR, Float32
+        0x40, 0x40, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x1C, 0xCC, 0xCD,
+    ];
+
+    #[test]
+    fn decode_batch() {
+        let mut out = [AccelerationHr {
+            x: 0.0_f32,
+            y: 0.0,
+            z: 0.0,
+        }; 4];
+        let n = AccelerationHr::<f32>::decode_batch(&WIRE_BYTES, &mut out);
+        assert_eq!(n, Ok(3));
+        assert_relative_eq!(out[0].x, 1.0);
+        assert_relative_eq!(out[0].y, 2.0);
+        assert_relative_eq!(out[1].x, -1.0);
+        assert_relative_eq!(out[1].y, -2.0);
+        assert_relative_eq!(out[2].x, 0.0);
+        for s in out[..3].iter() {
+            assert_relative_eq!(s.z, 9.8);
+        }
+
+        // Stops when the output is full
+        let n = AccelerationHr::<f32>::decode_batch(&WIRE_BYTES, &mut out[..1]);
+        assert_eq!(n, Ok(1));
+
+        // Packets of another precision are skipped
+        let mut out = [AccelerationHr {
+            x: 0.0_f64,
+            y: 0.0,
+            z: 0.0,
+        }; 4];
+        let n = AccelerationHr::<f64>::decode_batch(&WIRE_BYTES, &mut out);
+        assert_eq!(n, Ok(0));
+
+        let n = AccelerationHr::<f64>::decode_batch(&WIRE_BYTES[..8], &mut out);
+        assert_eq!(n, Err(WireError::MissingBytes));
+    }
+
+    #[test]
+    fn decode_batch_matches_packet_iter() {
+        // A 2 kHz stream, many matching packets interleaved with others
+        let mut payload = [0_u8; 20 * WIRE_BYTES.len()];
+        for chunk in payload.chunks_exact_mut(WIRE_BYTES.len()) {
+            chunk.copy_from_slice(&WIRE_BYTES);
+        }
+
+        let zero = AccelerationHr {
+            x: 0.0_f32,
+            y: 0.0,
+            z: 0.0,
+        };
+        let mut expected = [zero; 60];
+        let mut n_expected = 0;
+        for pkt in MTData2PacketIter::new(&payload) {
+            let pkt = pkt.unwrap();
+            if pkt.data_id().data_type() == DataType::AccelerationHR {
+                expected[n_expected] = AccelerationHr::<f32>::from_be_slice(pkt.payload()).unwrap();
+                n_expected += 1;
+            }
+        }
+        assert_eq!(n_expected, 60);
+
+        let mut out = [zero; 64];
+        let n = AccelerationHr::<f32>::decode_batch(&payload, &mut out);
+        assert_eq!(n, Ok(n_expected));
+        assert_eq!(&out[..n_expected], &expected[..]);
+
+        // A truncated trailing packet is an error
+        let n = AccelerationHr::<f32>::decode_batch(&payload[..payload.len() - 1], &mut out);
+        assert_eq!(n, Err(WireError::MissingBytes));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::precision::PrecisionExt;
+use core::fmt;
+
+/// Contains the strapdown integrated orientation increment over the last
+/// output interval as a unit quaternion (q0, q1, q2, q3), q0 is the scalar part
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DeltaQ<T: PrecisionExt> {
+    pub q0: T,
+    pub q1: T,
+    pub q2: T,
+    pub q3: T,
+}
+
+impl<T: PrecisionExt> fmt::Display for DeltaQ<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Q0({:.3}), Q1({:.3}), Q2({:.3}), Q3({:.3})",
+            self.q0, self.q1, self.q2, self.q3,
+        )
+    }
+}
+
+precision_float32_4field_wire_impl!(DeltaQ, q0, q1, q2, q3);
+precision_float64_4field_wire_impl!(DeltaQ, q0, q1, q2, q3);
+precision_fp1220_4field_wire_impl!(DeltaQ, q0, q1, q2, q3);
+precision_fp1632_4field_wire_impl!(DeltaQ, q0, q1, q2, q3);
+
+macro_rules! float_accumulate {
+    ($ty:ty) => {
+        impl DeltaQ<$ty> {
+            /// No orientation change, the starting point for accumulating increments
+            pub const fn identity() -> Self {
+                DeltaQ {
+                    q0: 1.0,
+                    q1: 0.0,
+                    q2: 0.0,
+                    q3: 0.0,
+                }
+            }
+
+            /// Apply the next increment, returning the total orientation change.
+            ///
+            /// This is the quaternion product `self * next`. Rounding errors build up
+            /// over many increments, callers may want to renormalize the result.
+            pub fn accumulate(&self, next: &Self) -> Self {
+                let (a, b) = (self, next);
+                DeltaQ {
+                    q0: a.q0 * b.q0 - a.q1 * b.q1 - a.q2 * b.q2 - a.q3 * b.q3,
+                    q1: a.q0 * b.q1 + a.q1 * b.q0 + a.q2 * b.q3 - a.q3 * b.q2,
+                    q2: a.q0 * b.q2 - a.q1 * b.q3 + a.q2 * b.q0 + a.q3 * b.q1,
+                    q3: a.q0 * b.q3 + a.q1 * b.q2 - a.q2 * b.q1 + a.q3 * b.q0,
+                }
+            }
+        }
+    };
+}
+
+float_accumulate!(f32);
+float_accumulate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn accumulate() {
+        // Two 45 degree increments about z make a 90 degree rotation
+        let half = 22.5_f64.to_radians();
+        let dq = DeltaQ {
+            q0: libm::cos(half),
+            q1: 0.0,
+            q2: 0.0,
+            q3: libm::sin(half),
+        };
+        let total = [dq, dq]
+            .iter()
+            .fold(DeltaQ::<f64>::identity(), |acc, dq| acc.accumulate(dq));
+        assert_relative_eq!(total.q0, FRAC_1_SQRT_2, epsilon = 1e-12);
+        assert_relative_eq!(total.q1, 0.0);
+        assert_relative_eq!(total.q2, 0.0);
+        assert_relative_eq!(total.q3, FRAC_1_SQRT_2, epsilon = 1e-12);
+
+        // Non-commuting increments, 90 degrees about x then 90 degrees about y
+        let x = DeltaQ {
+            q0: FRAC_1_SQRT_2,
+            q1: FRAC_1_SQRT_2,
+            q2: 0.0,
+            q3: 0.0,
+        };
+        let y = DeltaQ {
+            q0: FRAC_1_SQRT_2,
+            q1: 0.0,
+            q2: FRAC_1_SQRT_2,
+            q3: 0.0,
+        };
+        let total = x.accumulate(&y);
+        assert_relative_eq!(total.q0, 0.5, epsilon = 1e-12);
+        assert_relative_eq!(total.q1, 0.5, epsilon = 1e-12);
+        assert_relative_eq!(total.q2, 0.5, epsilon = 1e-12);
+        assert_relative_eq!(total.q3, 0.5, epsilon = 1e-12);
+    }
+}
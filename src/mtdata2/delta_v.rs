@@ -0,0 +1,82 @@
+use crate::precision::PrecisionExt;
+use core::fmt;
+
+/// Contains the strapdown integrated velocity increment in x, y, and z axes
+/// in m/s over the last output interval
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DeltaV<T: PrecisionExt> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: PrecisionExt> fmt::Display for DeltaV<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X({:.3}), Y({:.3}), Z({:.3})", self.x, self.y, self.z)
+    }
+}
+
+precision_float32_3field_wire_impl!(DeltaV, x, y, z);
+precision_float64_3field_wire_impl!(DeltaV, x, y, z);
+precision_fp1220_3field_wire_impl!(DeltaV, x, y, z);
+precision_fp1632_3field_wire_impl!(DeltaV, x, y, z);
+
+macro_rules! float_accumulate {
+    ($ty:ty) => {
+        impl DeltaV<$ty> {
+            /// No velocity change, the starting point for accumulating increments
+            pub const fn zero() -> Self {
+                DeltaV {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                }
+            }
+
+            /// Add the next increment, returning the total velocity change
+            pub fn accumulate(&self, next: &Self) -> Self {
+                DeltaV {
+                    x: self.x + next.x,
+                    y: self.y + next.y,
+                    z: self.z + next.z,
+                }
+            }
+        }
+    };
+}
+
+float_accumulate!(f32);
+float_accumulate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn accumulate() {
+        let increments = [
+            DeltaV {
+                x: 0.01_f32,
+                y: -0.02,
+                z: 0.098,
+            },
+            DeltaV {
+                x: 0.01,
+                y: -0.01,
+                z: 0.098,
+            },
+            DeltaV {
+                x: 0.02,
+                y: 0.0,
+                z: 0.098,
+            },
+        ];
+        let total = increments
+            .iter()
+            .fold(DeltaV::<f32>::zero(), |acc, dv| acc.accumulate(dv));
+        assert_relative_eq!(total.x, 0.04);
+        assert_relative_eq!(total.y, -0.03);
+        assert_relative_eq!(total.z, 0.294);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::precision::PrecisionExt;
+use core::fmt;
+
+/// Contains the free acceleration vector in x, y, and z axes in m/s^2,
+/// the calibrated acceleration with the local gravity removed
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FreeAcceleration<T: PrecisionExt> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: PrecisionExt> fmt::Display for FreeAcceleration<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X({:.3}), Y({:.3}), Z({:.3})", self.x, self.y, self.z)
+    }
+}
+
+precision_float32_3field_wire_impl!(FreeAcceleration, x, y, z);
+precision_float64_3field_wire_impl!(FreeAcceleration, x, y, z);
+precision_fp1220_3field_wire_impl!(FreeAcceleration, x, y, z);
+precision_fp1632_3field_wire_impl!(FreeAcceleration, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wire::{DataType, MTData2Packet, Precision, WireError};
+    use approx::assert_relative_eq;
+
+    #[rustfmt::skip]
+    static WIRE_BYTES: [u8; 15] = [
+        // FreeAcceleration, Float32
+        0x40, 0x30, 0x0C, 0x3F, 0x00, 0x00, 0x00, 0xBE, 0x80, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00,
+    ];
+
+    #[rustfmt::skip]
+    static WIRE_BYTES_F64: [u8; 27] = [
+        // FreeAcceleration, Float64
+        0x40, 0x33, 0x18,
+        0x3F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xBF, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x3F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn decode_packet() {
+        let p = MTData2Packet::new(&WIRE_BYTES[..]).unwrap();
+        assert_eq!(p.data_id().data_type(), DataType::FreeAcceleration);
+        assert_eq!(p.data_id().precision(), Precision::Float32);
+        let a = FreeAcceleration::<f32>::from_be_slice(p.payload()).unwrap();
+        assert_relative_eq!(a.x, 0.5);
+        assert_relative_eq!(a.y, -0.25);
+        assert_relative_eq!(a.z, 1.5);
+
+        let p = MTData2Packet::new(&WIRE_BYTES_F64[..]).unwrap();
+        assert_eq!(p.data_id().data_type(), DataType::FreeAcceleration);
+        assert_eq!(p.data_id().precision(), Precision::Float64);
+        let a = FreeAcceleration::<f64>::from_be_slice(p.payload()).unwrap();
+        assert_relative_eq!(a.x, 0.5);
+        assert_relative_eq!(a.y, -0.25);
+        assert_relative_eq!(a.z, 1.5);
+    }
+
+    #[test]
+    fn decode_short_payload() {
+        let p = MTData2Packet::new(&WIRE_BYTES[..]).unwrap();
+        assert_eq!(
+            FreeAcceleration::<f32>::from_be_slice(&p.payload()[..8]),
+            Err(WireError::MissingBytes)
+        );
+    }
+}
@@ -1,27 +1,43 @@
 pub mod acceleration;
+pub mod acceleration_hr;
 pub mod altitude_ellipsoid;
+pub mod delta_q;
+pub mod delta_v;
 pub mod euler_angles;
+pub mod free_acceleration;
 pub mod lat_lon;
 pub mod magnetic_field;
 pub mod packet_counter;
 pub mod position_ecef;
+pub mod quaternion;
 pub mod rate_of_turn;
+pub mod rate_of_turn_hr;
+pub mod rotation_matrix;
 pub mod sample_time_coarse;
 pub mod sample_time_fine;
 pub mod status_word;
+pub mod temperature;
 pub mod utc_time;
 pub mod velocity_xyz;
 
 pub use acceleration::*;
+pub use acceleration_hr::*;
 pub use altitude_ellipsoid::*;
+pub use delta_q::*;
+pub use delta_v::*;
 pub use euler_angles::*;
+pub use free_acceleration::*;
 pub use lat_lon::*;
 pub use magnetic_field::*;
 pub use packet_counter::*;
 pub use position_ecef::*;
+pub use quaternion::*;
 pub use rate_of_turn::*;
+pub use rate_of_turn_hr::*;
+pub use rotation_matrix::*;
 pub use sample_time_coarse::*;
 pub use sample_time_fine::*;
 pub use status_word::*;
+pub use temperature::*;
 pub use utc_time::*;
 pub use velocity_xyz::*;
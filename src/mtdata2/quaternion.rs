@@ -0,0 +1,144 @@
+use crate::mtdata2::EulerAngles;
+use crate::precision::PrecisionExt;
+use core::fmt;
+
+/// Contains the unit quaternion (q0, q1, q2, q3) that represents the
+/// orientation of the MT, q0 is the scalar part
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Quaternion<T: PrecisionExt> {
+    pub q0: T,
+    pub q1: T,
+    pub q2: T,
+    pub q3: T,
+}
+
+impl<T: PrecisionExt> fmt::Display for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Q0({:.3}), Q1({:.3}), Q2({:.3}), Q3({:.3})",
+            self.q0, self.q1, self.q2, self.q3,
+        )
+    }
+}
+
+precision_float32_4field_wire_impl!(Quaternion, q0, q1, q2, q3);
+precision_float64_4field_wire_impl!(Quaternion, q0, q1, q2, q3);
+precision_fp1220_4field_wire_impl!(Quaternion, q0, q1, q2, q3);
+precision_fp1632_4field_wire_impl!(Quaternion, q0, q1, q2, q3);
+
+macro_rules! float_conversions {
+    ($ty:ty, $atan2:path, $asin:path) => {
+        impl Quaternion<$ty> {
+            /// Convert to roll, pitch and yaw in degrees, using the same
+            /// conventions as the device's EulerAngles output
+            pub fn to_euler_angles(&self) -> EulerAngles<$ty> {
+                let Quaternion { q0, q1, q2, q3 } = *self;
+                let roll = $atan2(2.0 * (q2 * q3 + q0 * q1), 2.0 * (q0 * q0 + q3 * q3) - 1.0);
+                // Clamp to guard against rounding just outside of asin's domain
+                let pitch = -$asin((2.0 * (q1 * q3 - q0 * q2)).clamp(-1.0, 1.0));
+                let yaw = $atan2(2.0 * (q1 * q2 + q0 * q3), 2.0 * (q0 * q0 + q1 * q1) - 1.0);
+                EulerAngles {
+                    roll: roll.to_degrees(),
+                    pitch: pitch.to_degrees(),
+                    yaw: yaw.to_degrees(),
+                }
+            }
+
+            /// Convert to the row-major 3x3 rotation matrix that rotates the
+            /// sensor frame to the local earth frame
+            pub fn to_rotation_matrix(&self) -> [[$ty; 3]; 3] {
+                let Quaternion { q0, q1, q2, q3 } = *self;
+                [
+                    [
+                        q0 * q0 + q1 * q1 - q2 * q2 - q3 * q3,
+                        2.0 * (q1 * q2 - q0 * q3),
+                        2.0 * (q1 * q3 + q0 * q2),
+                    ],
+                    [
+                        2.0 * (q1 * q2 + q0 * q3),
+                        q0 * q0 - q1 * q1 + q2 * q2 - q3 * q3,
+                        2.0 * (q2 * q3 - q0 * q1),
+                    ],
+                    [
+                        2.0 * (q1 * q3 - q0 * q2),
+                        2.0 * (q2 * q3 + q0 * q1),
+                        q0 * q0 - q1 * q1 - q2 * q2 + q3 * q3,
+                    ],
+                ]
+            }
+        }
+    };
+}
+
+float_conversions!(f32, libm::atan2f, libm::asinf);
+float_conversions!(f64, libm::atan2, libm::asin);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn identity() {
+        let q = Quaternion {
+            q0: 1.0_f32,
+            q1: 0.0,
+            q2: 0.0,
+            q3: 0.0,
+        };
+        let e = q.to_euler_angles();
+        assert_relative_eq!(e.roll, 0.0);
+        assert_relative_eq!(e.pitch, 0.0);
+        assert_relative_eq!(e.yaw, 0.0);
+        let m = q.to_rotation_matrix();
+        for (r, row) in m.iter().enumerate() {
+            for (c, v) in row.iter().enumerate() {
+                assert_relative_eq!(*v, if r == c { 1.0 } else { 0.0 });
+            }
+        }
+    }
+
+    #[test]
+    fn single_axis_rotations() {
+        // 90 degrees about z
+        let q = Quaternion {
+            q0: FRAC_1_SQRT_2,
+            q1: 0.0,
+            q2: 0.0,
+            q3: FRAC_1_SQRT_2,
+        };
+        let e = q.to_euler_angles();
+        assert_relative_eq!(e.roll, 0.0);
+        assert_relative_eq!(e.pitch, 0.0);
+        assert_relative_eq!(e.yaw, 90.0, epsilon = 1e-9);
+        let m = q.to_rotation_matrix();
+        assert_relative_eq!(m[0][1], -1.0, epsilon = 1e-9);
+        assert_relative_eq!(m[1][0], 1.0, epsilon = 1e-9);
+        assert_relative_eq!(m[2][2], 1.0, epsilon = 1e-9);
+
+        // 90 degrees about y, at the asin domain boundary
+        let q = Quaternion {
+            q0: FRAC_1_SQRT_2,
+            q1: 0.0,
+            q2: FRAC_1_SQRT_2,
+            q3: 0.0,
+        };
+        let e = q.to_euler_angles();
+        assert_relative_eq!(e.pitch, 90.0, epsilon = 1e-6);
+
+        // 30 degrees about x
+        let half = 15.0_f64.to_radians();
+        let q = Quaternion {
+            q0: libm::cos(half),
+            q1: libm::sin(half),
+            q2: 0.0,
+            q3: 0.0,
+        };
+        let e = q.to_euler_angles();
+        assert_relative_eq!(e.roll, 30.0, epsilon = 1e-9);
+        assert_relative_eq!(e.pitch, 0.0, epsilon = 1e-9);
+        assert_relative_eq!(e.yaw, 0.0, epsilon = 1e-9);
+    }
+}
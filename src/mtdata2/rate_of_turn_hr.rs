@@ -0,0 +1,79 @@
+use crate::precision::PrecisionExt;
+use core::fmt;
+
+/// Contains the high rate rate of turn vector in x, y, and z axes in rad/s,
+/// output at rates up to 2000 Hz
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RateOfTurnHr<T: PrecisionExt> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: PrecisionExt> fmt::Display for RateOfTurnHr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X({:.3}), Y({:.3}), Z({:.3})", self.x, self.y, self.z)
+    }
+}
+
+precision_float32_3field_wire_impl!(RateOfTurnHr, x, y, z);
+precision_float64_3field_wire_impl!(RateOfTurnHr, x, y, z);
+precision_fp1220_3field_wire_impl!(RateOfTurnHr, x, y, z);
+precision_fp1632_3field_wire_impl!(RateOfTurnHr, x, y, z);
+batch_decode_3field_impl!(RateOfTurnHr, RateOfTurnHr);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static WIRE_BYTES: [u8; 50] = [
+        // RateOfTurnHR, Fp1220
+        0x80, 0x41, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03,
+        // AccelerationHR, Fp1220
+        0x40, 0x41, 0x0C, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06,
+        // RateOfTurnHR, Fp1220
+        0x80, 0x41, 0x0C, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x09,
+        // PacketCounter
+        0x10, 0x20, 0x02, 0x01, 0x14,
+    ];
+
+    #[test]
+    fn decode_batch() {
+        let mut out = [RateOfTurnHr {
+            x: 0_u32,
+            y: 0,
+            z: 0,
+        }; 3];
+        let n = RateOfTurnHr::<u32>::decode_batch(&WIRE_BYTES, &mut out);
+        assert_eq!(n, Ok(2));
+        assert_eq!(out[0], RateOfTurnHr { x: 1, y: 2, z: 3 });
+        assert_eq!(out[1], RateOfTurnHr { x: 7, y: 8, z: 9 });
+
+        let mut out = [RateOfTurnHr {
+            x: 0.0_f32,
+            y: 0.0,
+            z: 0.0,
+        }; 3];
+        let n = RateOfTurnHr::<f32>::decode_batch(&WIRE_BYTES, &mut out);
+        assert_eq!(n, Ok(0));
+    }
+
+    #[test]
+    fn decode_batch_any_coordinate_system() {
+        #[rustfmt::skip]
+        static NED_WIRE_BYTES: [u8; 15] = [
+            // RateOfTurnHR, Fp1220, NED
+            0x80, 0x45, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03,
+        ];
+        let mut out = [RateOfTurnHr {
+            x: 0_u32,
+            y: 0,
+            z: 0,
+        }; 1];
+        let n = RateOfTurnHr::<u32>::decode_batch(&NED_WIRE_BYTES, &mut out);
+        assert_eq!(n, Ok(1));
+        assert_eq!(out[0], RateOfTurnHr { x: 1, y: 2, z: 3 });
+    }
+}
@@ -0,0 +1,39 @@
+use crate::precision::PrecisionExt;
+use core::fmt;
+
+/// Contains the direction cosine matrix that represents the
+/// orientation of the MT.
+///
+/// The elements are sent in column-major order:
+/// ```text
+/// | a d g |
+/// | b e h |
+/// | c f i |
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RotationMatrix<T: PrecisionExt> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub e: T,
+    pub f: T,
+    pub g: T,
+    pub h: T,
+    pub i: T,
+}
+
+impl<T: PrecisionExt> fmt::Display for RotationMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A({:.3}), B({:.3}), C({:.3}), D({:.3}), E({:.3}), F({:.3}), G({:.3}), H({:.3}), I({:.3})",
+            self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h, self.i,
+        )
+    }
+}
+
+precision_float32_9field_wire_impl!(RotationMatrix, a, b, c, d, e, f, g, h, i);
+precision_float64_9field_wire_impl!(RotationMatrix, a, b, c, d, e, f, g, h, i);
+precision_fp1220_9field_wire_impl!(RotationMatrix, a, b, c, d, e, f, g, h, i);
+precision_fp1632_9field_wire_impl!(RotationMatrix, a, b, c, d, e, f, g, h, i);
@@ -0,0 +1,42 @@
+use crate::precision::PrecisionExt;
+use crate::wire::Precision;
+use core::fmt;
+
+/// Contains the internal temperature of the MT in degrees Celsius
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Temperature<T: PrecisionExt>(pub T);
+
+impl<T: PrecisionExt> fmt::Display for Temperature<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match T::PRECISION {
+            Precision::Float32 | Precision::Float64 => write!(f, "{:.2} °C", self.0),
+            // The fixed point variants hold the raw wire integer, printed without a unit
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+precision_float32_newtype_wire_impl!(Temperature);
+precision_float64_newtype_wire_impl!(Temperature);
+precision_fp1220_newtype_wire_impl!(Temperature);
+precision_fp1632_newtype_wire_impl!(Temperature);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::string::ToString;
+
+    #[test]
+    fn display() {
+        assert_eq!(Temperature(23.456_f32).to_string(), "23.46 °C");
+        assert_eq!(Temperature(-4.5_f64).to_string(), "-4.50 °C");
+        assert_eq!(Temperature(0x0177_0000_u32).to_string(), "24576000");
+        assert_eq!(
+            Temperature(0x0017_7000_0000_u64).to_string(),
+            "100663296000"
+        );
+    }
+}
@@ -101,4 +101,20 @@ impl UtcTime {
             })
         }
     }
+
+    pub fn to_be_slice(&self, bytes: &mut [u8]) -> Result<(), WireError> {
+        if bytes.len() < Self::WIRE_SIZE {
+            Err(WireError::MissingBytes)
+        } else {
+            BigEndian::write_u32(&mut bytes[field::NS], self.ns);
+            BigEndian::write_u16(&mut bytes[field::YEAR], self.year);
+            bytes[field::MONTH] = self.month;
+            bytes[field::DAY] = self.day;
+            bytes[field::HOUR] = self.hour;
+            bytes[field::MINUTE] = self.minute;
+            bytes[field::SECOND] = self.second;
+            bytes[field::FLAGS] = self.flags.0;
+            Ok(())
+        }
+    }
 }
@@ -1,6 +1,12 @@
+pub use crate::data_poller::DataPoller;
 pub use crate::decoder::Decoder;
-pub use crate::message::{BusId, Frame, MessageDecode, MessageExt, MessageId, PayloadLength};
+pub use crate::encoder::Encoder;
+pub use crate::message::{
+    BusId, Frame, KnownMessageId, MessageDecode, MessageEncode, MessageExt, MessageId,
+    PayloadLength,
+};
 pub use crate::messages::*;
 pub use crate::mtdata2::*;
 pub use crate::precision::PrecisionExt;
+pub use crate::wake_up_handler::WakeUpHandler;
 pub use crate::wire::{CoordinateSystem, DataId, DataType, Precision};
@@ -0,0 +1,115 @@
+//! Power-on WakeUp handshake helper
+//!
+//! At power-on (or after a reset) the device sends a WakeUp message and waits
+//! 500 ms for a WakeUpAck before switching to Measurement State.
+//! Acknowledging it keeps the device in Config State, so it can be reconfigured
+//! without first having to send a GoToConfig.
+
+use crate::decoder::{Decoder, Error};
+use crate::encoder::encode_frame;
+use crate::message::{BusId, Frame, MessageEncode, MessageExt};
+use crate::messages::{WakeUp, WakeUpAck};
+
+/// Size of the WakeUpAck frame written in response to a WakeUp
+const ACK_FRAME_SIZE: usize = Frame::<&[u8]>::buffer_len(WakeUpAck::MAX_PAYLOAD_LEN);
+
+#[derive(Debug)]
+pub struct WakeUpHandler<B: AsRef<[u8]> + AsMut<[u8]>> {
+    decoder: Decoder<B>,
+    acknowledged: bool,
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> WakeUpHandler<B> {
+    pub fn new(decoder: Decoder<B>) -> Self {
+        WakeUpHandler {
+            decoder,
+            acknowledged: false,
+        }
+    }
+
+    /// Returns true once a WakeUp has been received and acknowledged
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    pub fn into_inner(self) -> Decoder<B> {
+        self.decoder
+    }
+
+    /// Decode a byte received from the device.
+    ///
+    /// When a WakeUp frame is decoded, a WakeUpAck frame is immediately passed to `write`.
+    /// The caller should feed bytes as they arrive so that the acknowledgement
+    /// is sent within the device's 500 ms window.
+    ///
+    /// Returns true once the WakeUp has been acknowledged.
+    pub fn handle<E, F>(&mut self, byte: u8, mut write: F) -> Result<bool, E>
+    where
+        E: From<Error>,
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
+        let is_wake_up = match self.decoder.decode(byte)? {
+            Some(f) => f.message_id() == WakeUp::MSG_ID,
+            None => false,
+        };
+        if is_wake_up {
+            let mut ack = [0_u8; ACK_FRAME_SIZE];
+            encode_frame(&WakeUpAck, BusId::MASTER, &mut ack).map_err(Error::from)?;
+            write(&ack)?;
+            self.acknowledged = true;
+        }
+        Ok(self.acknowledged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    static WAKE_UP_MSG: [u8; 5] = [0xFA, 0xFF, 0x3E, 0x00, 0xC3];
+    static WAKE_UP_ACK_MSG: [u8; 5] = [0xFA, 0xFF, 0x3F, 0x00, 0xC2];
+
+    #[test]
+    fn acknowledge_wake_up() {
+        let mut buffer = [0_u8; 512];
+        let dec = Decoder::new(&mut buffer[..]).unwrap();
+        let mut handler = WakeUpHandler::new(dec);
+        let mut written = [0_u8; 5];
+        let mut write_count = 0;
+
+        for byte in WAKE_UP_MSG.iter() {
+            let acked = handler
+                .handle(*byte, |bytes: &[u8]| -> Result<(), Error> {
+                    written.copy_from_slice(bytes);
+                    write_count += 1;
+                    Ok(())
+                })
+                .unwrap();
+            assert_eq!(acked, write_count == 1);
+        }
+
+        assert!(handler.is_acknowledged());
+        assert_eq!(write_count, 1);
+        assert_eq!(written, WAKE_UP_ACK_MSG);
+        assert_eq!(handler.into_inner().count(), 1);
+    }
+
+    #[test]
+    fn ignore_other_frames() {
+        let go_to_config_ack = [0xFA, 0xFF, 0x31, 0x00, 0xD0];
+        let mut buffer = [0_u8; 512];
+        let dec = Decoder::new(&mut buffer[..]).unwrap();
+        let mut handler = WakeUpHandler::new(dec);
+
+        for byte in go_to_config_ack.iter() {
+            let acked = handler
+                .handle(*byte, |_: &[u8]| -> Result<(), Error> {
+                    panic!("unexpected write")
+                })
+                .unwrap();
+            assert!(!acked);
+        }
+        assert!(!handler.is_acknowledged());
+    }
+}
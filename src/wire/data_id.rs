@@ -77,6 +77,7 @@ enum_with_unknown! {
 
         // OrientationGroup     = 0x20xy
         Quaternion              = 0x2010,
+        RotationMatrix          = 0x2020,
         EulerAngles             = 0x2030, // degrees
 
         // AccelerationGroup    = 0x40xy
@@ -1,10 +1,12 @@
 pub mod data_id;
 pub mod mtdata2_packet;
 pub mod output_config;
+pub mod sync_setting;
 
 pub use data_id::*;
 pub use mtdata2_packet::*;
 pub use output_config::*;
+pub use sync_setting::*;
 
 pub(crate) type Field = ::core::ops::Range<usize>;
 pub(crate) type Rest = ::core::ops::RangeFrom<usize>;
@@ -15,6 +17,12 @@ pub(crate) mod field32 {
     pub const F0: Field = 0..4;
     pub const F1: Field = 4..8;
     pub const F2: Field = 8..12;
+    pub const F3: Field = 12..16;
+    pub const F4: Field = 16..20;
+    pub const F5: Field = 20..24;
+    pub const F6: Field = 24..28;
+    pub const F7: Field = 28..32;
+    pub const F8: Field = 32..36;
 }
 
 pub(crate) mod field64 {
@@ -23,6 +31,23 @@ pub(crate) mod field64 {
     pub const F0: Field = 0..8;
     pub const F1: Field = 8..16;
     pub const F2: Field = 16..24;
+    pub const F3: Field = 24..32;
+    pub const F4: Field = 32..40;
+    pub const F5: Field = 40..48;
+    pub const F6: Field = 48..56;
+    pub const F7: Field = 56..64;
+    pub const F8: Field = 64..72;
+}
+
+/// Interpret a fixed size ASCII field, padded with spaces or NUL bytes, as a string.
+///
+/// The string ends at the first byte that is not valid UTF-8.
+pub(crate) fn ascii_field(bytes: &[u8]) -> &str {
+    let s = match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => core::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+    };
+    s.trim_end_matches([' ', '\0'])
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, err_derive::Error)]
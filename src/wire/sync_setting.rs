@@ -0,0 +1,333 @@
+use crate::wire::WireError;
+use byteorder::{BigEndian, ByteOrder};
+
+enum_with_unknown! {
+    /// The action taken when a sync event occurs
+    pub enum SyncFunction(u8) {
+        /// Send a trigger indication when a sync input event occurs
+        TriggerIndication = 3,
+        /// Send a pulse on the sync output line at each sample interval
+        IntervalTransitionMeasurement = 4,
+        /// Send the latest available data when a sync input event occurs
+        SendLatest = 8,
+        /// Estimate the clock bias using the sync input pulses
+        ClockBiasEstimation = 9,
+        /// Start sampling when a sync input event occurs
+        StartSampling = 11,
+    }
+}
+
+enum_with_unknown! {
+    /// The physical or virtual line a sync setting applies to
+    pub enum SyncLine(u8) {
+        In1 = 0,
+        In2 = 1,
+        /// Bidirectional sync line, configured as input
+        Bi1In = 2,
+        ClockIn = 3,
+        CtsIn = 4,
+        GnssClockIn = 5,
+        ExtTimepulseIn = 6,
+        /// Triggered by a ReqData message
+        ReqData = 7,
+        /// The 1PPS signal of the GNSS receiver
+        Gnss1Pps = 8,
+        Button = 9,
+        Out1 = 10,
+        Out2 = 11,
+        /// Bidirectional sync line, configured as output
+        Bi1Out = 12,
+        RtsOut = 13,
+    }
+}
+
+enum_with_unknown! {
+    /// The edge or pulse polarity of a sync line
+    pub enum SyncPolarity(u8) {
+        None = 0,
+        /// Rising edge for inputs, positive pulse for outputs
+        RisingEdge = 1,
+        /// Falling edge for inputs, negative pulse for outputs
+        FallingEdge = 2,
+        /// Both edges for inputs, toggle for outputs
+        BothEdges = 3,
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SyncSetting {
+    pub function: SyncFunction,
+    pub line: SyncLine,
+    pub polarity: SyncPolarity,
+    /// Only act on the first sync event
+    pub trigger_once: bool,
+    /// Number of initial events to skip
+    pub skip_first: u16,
+    /// Number of events to skip between actions
+    pub skip_factor: u16,
+    /// Output pulse width in units of 100 us
+    pub pulse_width: u16,
+    /// Delay or offset in units of 100 us
+    pub delay: u16,
+}
+
+impl SyncSetting {
+    pub fn new(function: SyncFunction, line: SyncLine, polarity: SyncPolarity) -> Self {
+        SyncSetting {
+            function,
+            line,
+            polarity,
+            trigger_once: false,
+            skip_first: 0,
+            skip_factor: 0,
+            pulse_width: 0,
+            delay: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct WireSyncSetting<T: AsRef<[u8]>> {
+    buffer: T,
+}
+
+mod field {
+    use crate::wire::Field;
+
+    pub const FUNCTION: usize = 0;
+    pub const LINE: usize = 1;
+    pub const POLARITY: usize = 2;
+    pub const TRIGGER_ONCE: usize = 3;
+    pub const SKIP_FIRST: Field = 4..6;
+    pub const SKIP_FACTOR: Field = 6..8;
+    pub const PULSE_WIDTH: Field = 8..10;
+    pub const DELAY: Field = 10..12;
+}
+
+impl<T: AsRef<[u8]>> WireSyncSetting<T> {
+    pub const WIRE_SIZE: usize = field::DELAY.end;
+
+    pub fn new_unchecked(buffer: T) -> WireSyncSetting<T> {
+        WireSyncSetting { buffer }
+    }
+
+    pub fn new(buffer: T) -> Result<WireSyncSetting<T>, WireError> {
+        let f = Self::new_unchecked(buffer);
+        f.check_len()?;
+        Ok(f)
+    }
+
+    pub fn check_len(&self) -> Result<(), WireError> {
+        let len = self.buffer.as_ref().len();
+        if len < Self::WIRE_SIZE {
+            Err(WireError::MissingBytes)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.buffer
+    }
+
+    #[inline]
+    pub fn buffer_len() -> usize {
+        Self::WIRE_SIZE
+    }
+
+    #[inline]
+    pub fn function(&self) -> SyncFunction {
+        let data = self.buffer.as_ref();
+        SyncFunction::from(data[field::FUNCTION])
+    }
+
+    #[inline]
+    pub fn line(&self) -> SyncLine {
+        let data = self.buffer.as_ref();
+        SyncLine::from(data[field::LINE])
+    }
+
+    #[inline]
+    pub fn polarity(&self) -> SyncPolarity {
+        let data = self.buffer.as_ref();
+        SyncPolarity::from(data[field::POLARITY])
+    }
+
+    #[inline]
+    pub fn trigger_once(&self) -> bool {
+        let data = self.buffer.as_ref();
+        data[field::TRIGGER_ONCE] != 0
+    }
+
+    #[inline]
+    pub fn skip_first(&self) -> u16 {
+        let data = self.buffer.as_ref();
+        BigEndian::read_u16(&data[field::SKIP_FIRST])
+    }
+
+    #[inline]
+    pub fn skip_factor(&self) -> u16 {
+        let data = self.buffer.as_ref();
+        BigEndian::read_u16(&data[field::SKIP_FACTOR])
+    }
+
+    #[inline]
+    pub fn pulse_width(&self) -> u16 {
+        let data = self.buffer.as_ref();
+        BigEndian::read_u16(&data[field::PULSE_WIDTH])
+    }
+
+    #[inline]
+    pub fn delay(&self) -> u16 {
+        let data = self.buffer.as_ref();
+        BigEndian::read_u16(&data[field::DELAY])
+    }
+
+    #[inline]
+    pub fn sync_setting(&self) -> SyncSetting {
+        SyncSetting {
+            function: self.function(),
+            line: self.line(),
+            polarity: self.polarity(),
+            trigger_once: self.trigger_once(),
+            skip_first: self.skip_first(),
+            skip_factor: self.skip_factor(),
+            pulse_width: self.pulse_width(),
+            delay: self.delay(),
+        }
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> WireSyncSetting<T> {
+    #[inline]
+    pub fn set_function(&mut self, value: SyncFunction) {
+        let data = self.buffer.as_mut();
+        data[field::FUNCTION] = value.into();
+    }
+
+    #[inline]
+    pub fn set_line(&mut self, value: SyncLine) {
+        let data = self.buffer.as_mut();
+        data[field::LINE] = value.into();
+    }
+
+    #[inline]
+    pub fn set_polarity(&mut self, value: SyncPolarity) {
+        let data = self.buffer.as_mut();
+        data[field::POLARITY] = value.into();
+    }
+
+    #[inline]
+    pub fn set_trigger_once(&mut self, value: bool) {
+        let data = self.buffer.as_mut();
+        data[field::TRIGGER_ONCE] = value as u8;
+    }
+
+    #[inline]
+    pub fn set_skip_first(&mut self, value: u16) {
+        let data = self.buffer.as_mut();
+        BigEndian::write_u16(&mut data[field::SKIP_FIRST], value);
+    }
+
+    #[inline]
+    pub fn set_skip_factor(&mut self, value: u16) {
+        let data = self.buffer.as_mut();
+        BigEndian::write_u16(&mut data[field::SKIP_FACTOR], value);
+    }
+
+    #[inline]
+    pub fn set_pulse_width(&mut self, value: u16) {
+        let data = self.buffer.as_mut();
+        BigEndian::write_u16(&mut data[field::PULSE_WIDTH], value);
+    }
+
+    #[inline]
+    pub fn set_delay(&mut self, value: u16) {
+        let data = self.buffer.as_mut();
+        BigEndian::write_u16(&mut data[field::DELAY], value);
+    }
+
+    #[inline]
+    pub fn set_sync_setting(&mut self, value: SyncSetting) {
+        self.set_function(value.function);
+        self.set_line(value.line);
+        self.set_polarity(value.polarity);
+        self.set_trigger_once(value.trigger_once);
+        self.set_skip_first(value.skip_first);
+        self.set_skip_factor(value.skip_factor);
+        self.set_pulse_width(value.pulse_width);
+        self.set_delay(value.delay);
+    }
+}
+
+impl<T: AsRef<[u8]>> AsRef<[u8]> for WireSyncSetting<T> {
+    fn as_ref(&self) -> &[u8] {
+        self.buffer.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct WireSyncSettingIterator<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> WireSyncSettingIterator<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        WireSyncSettingIterator { buffer }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = SyncSetting> + 'a {
+        self.buffer
+            .chunks_exact(WireSyncSetting::<&[u8]>::WIRE_SIZE)
+            // unchecked ok, only an error if not WIRE_SIZE bytes in length
+            .map(|chunk| WireSyncSetting::new_unchecked(chunk).sync_setting())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    static WIRE_BYTES: [u8; 12] = [
+        0x03, 0x00, 0x01, 0x00,
+        0x00, 0x02, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x0A,
+    ];
+
+    fn setting() -> SyncSetting {
+        SyncSetting {
+            skip_first: 2,
+            skip_factor: 4,
+            delay: 10,
+            ..SyncSetting::new(
+                SyncFunction::TriggerIndication,
+                SyncLine::In1,
+                SyncPolarity::RisingEdge,
+            )
+        }
+    }
+
+    #[test]
+    fn construct() {
+        let mut bytes = [0xFF; 12];
+        let mut w = WireSyncSetting::new_unchecked(&mut bytes[..]);
+        assert_eq!(w.check_len(), Ok(()));
+        w.set_sync_setting(setting());
+        assert_eq!(w.into_inner(), &WIRE_BYTES[..]);
+    }
+
+    #[test]
+    fn deconstruct() {
+        let w = WireSyncSetting::new(&WIRE_BYTES[..]).unwrap();
+        assert_eq!(w.sync_setting(), setting());
+    }
+
+    #[test]
+    fn missing_bytes() {
+        let bytes = [0xFF; 12 - 1];
+        let w = WireSyncSetting::new(&bytes[..]);
+        assert_eq!(w.unwrap_err(), WireError::MissingBytes);
+    }
+}